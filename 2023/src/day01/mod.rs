@@ -55,13 +55,8 @@ fn find_in_string(line: &str, numbers: &[String]) -> u32 {
     panic!("No digit or number found in {}", line)
 }
 
-pub fn main() -> (u32, u32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
+aoc_common::solution!(parse_input -> (u32, u32));
 
-    (part1, part2)
-}
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -100,7 +95,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 54644);
         assert_eq!(part2, 53348);
@@ -66,13 +66,7 @@ fn parse_input(input: &str) -> (u32, u32) {
     (part1, part2)
 }
 
-pub fn main() -> (u32, u32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (u32, u32));
 
 #[cfg(test)]
 mod tests {
@@ -103,7 +97,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 527364);
         assert_eq!(part2, 79026871);
@@ -1,7 +1,8 @@
-use std::collections::HashMap;
-
+use aoc_toolbox::{
+    cycle,
+    graph::{Graph, Labels},
+};
 use gcd::Gcd;
-use itertools::Itertools;
 use parse_display::{Display, FromStr};
 
 #[derive(Display, FromStr, PartialEq, Debug)]
@@ -14,76 +15,50 @@ struct Node {
 
 fn parse_input(input: &str) -> (u32, usize) {
     let mut lines = input.trim().lines();
-    let instructions = lines.next().unwrap().chars().collect_vec();
+    // index of the successor to follow, the left one coming first
+    let instructions: Vec<usize> = lines
+        .next()
+        .unwrap()
+        .chars()
+        .map(|c| if c == 'L' { 0 } else { 1 })
+        .collect();
     lines.next();
-    let mut network: HashMap<String, Node> = HashMap::new();
 
+    let mut labels = Labels::default();
+    let mut network = Graph::default();
     for line in lines {
         let node: Node = line.parse().unwrap();
-        network.insert(node.id.clone(), node);
+        let id = labels.id(node.id);
+        network.add_edge(id, labels.id(node.left));
+        network.add_edge(id, labels.id(node.right));
     }
-    let mut current_id: String = "AAA".into();
+
+    let mut node = labels.get(&"AAA".to_string()).unwrap();
     let mut part1: u32 = 0;
-    loop {
-        if current_id == "ZZZ" {
-            break;
-        }
-        let instruction = instructions[part1 as usize % (instructions.len())];
+    while labels.label(node) != "ZZZ" {
+        node = network.successors(node)[instructions[part1 as usize % instructions.len()]];
         part1 += 1;
-        let current_node = network.get(&current_id).unwrap();
-        current_id = match instruction {
-            'L' => current_node.left.clone(),
-            'R' => current_node.right.clone(),
-            _ => "".into(),
-        }
     }
 
-    let mut ghost_ids = network
-        .keys()
-        .filter(|&id| id.ends_with('A'))
-        .map(|id| id.to_owned())
-        .collect_vec();
-    let mut z_indexes: HashMap<usize, usize> = (0..ghost_ids.len()).map(|idx| (idx, 0)).collect();
-    let mut step_idx = 0;
-    loop {
-        ghost_ids.iter().enumerate().for_each(|(idx, id)| {
-            if id.ends_with('Z') {
-                z_indexes.entry(idx).and_modify(|z_idx| {
-                    if *z_idx == 0 {
-                        *z_idx = step_idx;
-                    }
-                });
-            }
-        });
-        if z_indexes.values().filter(|&v| *v != 0).collect_vec().len() == ghost_ids.len() {
-            break;
-        }
-        let instruction = instructions[step_idx % (instructions.len())];
-        step_idx += 1;
-        ghost_ids = ghost_ids
-            .iter()
-            .map(|id| {
-                let current_node = network.get(id).unwrap();
-                match instruction {
-                    'L' => current_node.left.clone(),
-                    'R' => current_node.right.clone(),
-                    _ => "".into(),
-                }
+    // each ghost walks a cycle of its nodes and positions in the instructions, and on the inputs
+    // it is on its only end node at the last step of every cycle, so all of them are on theirs
+    // after the `lcm` (https://en.wikipedia.org/wiki/Least_common_multiple#Using_the_greatest_common_divisor) of the lengths of the cycles
+    let part2 = (0..labels.len())
+        .filter(|&node| labels.label(node).ends_with('A'))
+        .map(|start| {
+            cycle::brent((start, 0), |&(node, i)| {
+                (
+                    network.successors(node)[instructions[i]],
+                    (i + 1) % instructions.len(),
+                )
             })
-            .collect_vec();
-    }
-    // `lcm` (https://en.wikipedia.org/wiki/Least_common_multiple#Using_the_greatest_common_divisor) of all members of `z_indexes`
-    let part2 = z_indexes.values().fold(1, |acc, &n| acc * (n / n.gcd(acc)));
+            .length
+        })
+        .fold(1, |acc, n| acc * (n / n.gcd(acc)));
     (part1, part2)
 }
 
-pub fn main() -> (u32, usize) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (u32, usize));
 
 #[cfg(test)]
 mod tests {
@@ -129,7 +104,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 16343);
         assert_eq!(part2, 15_299_095_336_639);
@@ -49,13 +49,7 @@ fn parse_input(input: &str) -> (i32, i32) {
     (part1, part2)
 }
 
-pub fn main() -> (i32, i32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (i32, i32));
 
 #[cfg(test)]
 mod tests {
@@ -79,7 +73,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 1868368343);
         assert_eq!(part2, 1022);
@@ -61,13 +61,7 @@ fn parse_input(input: &str) -> (u32, usize) {
     (part1, part2)
 }
 
-pub fn main() -> (u32, usize) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (u32, usize));
 
 #[cfg(test)]
 mod tests {
@@ -98,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 9591768);
         assert_eq!(part2, 746962097860);
@@ -81,13 +81,7 @@ fn parse_input(input: &str) -> (u64, u64) {
     (part1, part2)
 }
 
-pub fn main() -> (u64, u64) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (u64, u64));
 
 #[cfg(test)]
 mod tests {
@@ -114,7 +108,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 6949);
         assert_eq!(part2, 51456609952403);
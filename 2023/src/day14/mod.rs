@@ -1,5 +1,6 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
+use aoc_toolbox::cycle;
 use itertools::Itertools;
 use parse_display::{Display, FromStr};
 use strum::{EnumIter, IntoEnumIterator};
@@ -104,34 +105,9 @@ fn do_cycle(grid: &mut [Vec<Tile>]) {
 }
 
 fn part2(mut grid: Vec<Vec<Tile>>) -> u32 {
-    let mut grids: HashMap<String, (usize, u32)> = HashMap::new();
-    let mut idx = 0;
-
-    loop {
-        do_cycle(&mut grid);
-        idx += 1;
-
-        let grid_str = grid.iter().map(|l| l.iter().join("")).join("\n");
-        if let Some((cycle_start, _)) = grids.get(&grid_str) {
-            let cycle_length = idx - cycle_start;
-            let grid_idx = cycle_start + (1000000000 - cycle_start) % cycle_length;
-
-            return grids
-                .iter()
-                .find_map(
-                    |(_, &(idx, load))| {
-                        if idx == grid_idx {
-                            Some(load)
-                        } else {
-                            None
-                        }
-                    },
-                )
-                .unwrap();
-        }
+    cycle::advance(&mut grid, |grid| do_cycle(grid), 1000000000);
 
-        grids.insert(grid_str, (idx, get_load(&grid)));
-    }
+    get_load(&grid)
 }
 
 fn parse_input(input: &str) -> (u32, u32) {
@@ -154,13 +130,7 @@ fn parse_input(input: &str) -> (u32, u32) {
     (part1, part2)
 }
 
-pub fn main() -> (u32, u32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (u32, u32));
 
 #[cfg(test)]
 mod tests {
@@ -191,7 +161,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 108918);
         assert_eq!(part2, 100310);
@@ -3,7 +3,7 @@ use parse_display::{Display, FromStr};
 use rayon::prelude::*;
 
 #[derive(Display, FromStr, PartialEq, Eq, Hash, Debug, Clone, Copy)]
-enum Tile {
+pub enum Tile {
     #[display(".")]
     Empty,
     #[display("/")]
@@ -88,8 +88,8 @@ fn get_energized_tiles(grid: &[Vec<Tile>], beam: (isize, isize, Direction)) -> u
         .sum()
 }
 
-pub fn part1(input: &str) -> u32 {
-    let grid: Vec<Vec<Tile>> = input
+fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
         .trim()
         .lines()
         .map(|l| {
@@ -97,27 +97,19 @@ pub fn part1(input: &str) -> u32 {
                 .map(|c| c.to_string().parse().unwrap())
                 .collect_vec()
         })
-        .collect_vec();
-
-    get_energized_tiles(&grid, (0, 0, Direction::East))
+        .collect_vec()
 }
 
-pub fn part2(input: &str) -> u32 {
-    let grid: Vec<Vec<Tile>> = input
-        .trim()
-        .lines()
-        .map(|l| {
-            l.chars()
-                .map(|c| c.to_string().parse().unwrap())
-                .collect_vec()
-        })
-        .collect_vec();
+fn part1(grid: &[Vec<Tile>]) -> u32 {
+    get_energized_tiles(grid, (0, 0, Direction::East))
+}
 
+fn part2(grid: &[Vec<Tile>]) -> u32 {
     let rows = (0..grid.len())
         .into_par_iter()
         .map(|x| {
-            get_energized_tiles(&grid, (x as isize, 0, Direction::East)).max(get_energized_tiles(
-                &grid,
+            get_energized_tiles(grid, (x as isize, 0, Direction::East)).max(get_energized_tiles(
+                grid,
                 (x as isize, grid[0].len() as isize, Direction::West),
             ))
         })
@@ -126,8 +118,8 @@ pub fn part2(input: &str) -> u32 {
     let columns = (0..grid[0].len())
         .into_par_iter()
         .map(|y| {
-            get_energized_tiles(&grid, (0, y as isize, Direction::South)).max(get_energized_tiles(
-                &grid,
+            get_energized_tiles(grid, (0, y as isize, Direction::South)).max(get_energized_tiles(
+                grid,
                 (grid.len() as isize, y as isize, Direction::North),
             ))
         })
@@ -137,17 +129,7 @@ pub fn part2(input: &str) -> u32 {
     rows.max(columns)
 }
 
-pub fn parse_input(input: &str) -> (u32, u32) {
-    (part1(input), part2(input))
-}
-
-pub fn main() -> (u32, u32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse -> Vec<Vec<Tile>>; u32, u32);
 
 #[cfg(test)]
 mod tests {
@@ -170,17 +152,17 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let (part1, part2) = parse_input(EXAMPLE_INPUT);
+        let grid = parse(EXAMPLE_INPUT);
 
-        assert_eq!(part1, 46);
-        assert_eq!(part2, 51);
+        assert_eq!(part1(&grid), 46);
+        assert_eq!(part2(&grid), 51);
     }
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let grid = parse(include_str!("input.txt"));
 
-        assert_eq!(part1, 7939);
-        assert_eq!(part2, 8318);
+        assert_eq!(part1(&grid), 7939);
+        assert_eq!(part2(&grid), 8318);
     }
 }
@@ -163,35 +163,23 @@ pub fn shortest_path(
     unreachable!()
 }
 
-pub fn part1(input: &str) -> u32 {
-    let grid = input
+fn parse(input: &str) -> Vec<Vec<u32>> {
+    input
         .trim()
         .lines()
         .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec();
-    shortest_path(&grid, 1, 3)
+        .collect_vec()
 }
 
-pub fn part2(input: &str) -> u32 {
-    let grid = input
-        .trim()
-        .lines()
-        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec();
-    shortest_path(&grid, 4, 10)
+pub fn part1(grid: &[Vec<u32>]) -> u32 {
+    shortest_path(grid, 1, 3)
 }
 
-pub fn parse_input(input: &str) -> (u32, u32) {
-    (part1(input), part2(input))
+pub fn part2(grid: &[Vec<u32>]) -> u32 {
+    shortest_path(grid, 4, 10)
 }
 
-pub fn main() -> (u32, u32) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse -> Vec<Vec<u32>>; u32, u32);
 
 #[cfg(test)]
 mod tests {
@@ -217,17 +205,17 @@ mod tests {
 
     #[test]
     fn test_example() {
-        let (part1, part2) = parse_input(EXAMPLE_INPUT);
+        let grid = parse(EXAMPLE_INPUT);
 
-        assert_eq!(part1, 102);
-        assert_eq!(part2, 94);
+        assert_eq!(part1(&grid), 102);
+        assert_eq!(part2(&grid), 94);
     }
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let grid = parse(include_str!("input.txt"));
 
-        assert_eq!(part1, 635);
-        assert_eq!(part2, 734);
+        assert_eq!(part1(&grid), 635);
+        assert_eq!(part2(&grid), 734);
     }
 }
@@ -268,13 +268,7 @@ fn parse_input(input: &str) -> (usize, usize) {
     (part1, part2)
 }
 
-pub fn main() -> (usize, usize) {
-    let (part1, part2) = parse_input(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse_input -> (usize, usize));
 
 #[cfg(test)]
 mod tests {
@@ -312,7 +306,7 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let (part1, part2) = parse_input(include_str!("input.txt"));
 
         assert_eq!(part1, 319062);
         assert_eq!(part2, 118638369682135);
@@ -1,5 +1,10 @@
-use std::{cell::RefCell, collections::HashMap, ops::Not, rc::Rc};
+use std::collections::VecDeque;
 
+use aoc_toolbox::{
+    cycle,
+    graph::{Graph, Labels},
+};
+use gcd::Gcd;
 use parse_display::{Display, FromStr};
 
 #[derive(Display, FromStr, Eq, PartialEq, Debug, Clone)]
@@ -12,278 +17,170 @@ enum Module {
     Conjunction { name: String, destinations: String },
 }
 
-#[derive(Eq, PartialEq, Debug, Clone)]
-enum PulseType {
-    Low,
-    High,
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// Module only receiving pulses, such as `rx`
+    Untyped,
 }
 
-#[derive(Eq, PartialEq, Debug, Clone)]
-enum ParsedModule {
-    Broadcaster {
-        destinations: Vec<String>,
-    },
-    FlipFlop {
-        name: String,
-        destinations: Vec<String>,
-        on: bool,
-    },
-    Conjunction {
-        name: String,
-        destinations: Vec<String>,
-        pulses_received: HashMap<String, PulseType>,
-    },
+/// Modules as the nodes of a graph of the cables between them
+#[derive(Clone)]
+pub struct Network {
+    cables: Graph,
+    labels: Labels<String>,
+    kinds: Vec<Kind>,
+    /// Modules sending pulses to each module
+    inputs: Vec<Vec<usize>>,
 }
 
-fn parse_destinations(destinations: &str) -> Vec<String> {
-    destinations
-        .split(',')
-        .map(|s| s.trim().to_string())
-        .collect()
+/// What the modules remember of the pulses
+#[derive(Hash)]
+struct State {
+    /// Whether each flip-flop is on
+    on: Vec<bool>,
+    /// Whether the last pulse each conjunction received from each module was high
+    memory: Vec<Vec<bool>>,
 }
 
-impl From<Module> for ParsedModule {
-    fn from(module: Module) -> Self {
-        match module {
-            Module::Broadcaster { destinations } => ParsedModule::Broadcaster {
-                destinations: parse_destinations(&destinations),
-            },
-            Module::FlipFlop { name, destinations } => ParsedModule::FlipFlop {
-                name,
-                destinations: parse_destinations(&destinations),
-                on: false,
-            },
-            Module::Conjunction { name, destinations } => ParsedModule::Conjunction {
-                name,
-                destinations: parse_destinations(&destinations),
-                pulses_received: HashMap::new(),
-            },
-        }
+impl Network {
+    fn id(&self, label: &str) -> usize {
+        self.labels.get(&label.to_string()).unwrap()
     }
-}
-
-fn press_broadcast(
-    modules_map: &mut HashMap<String, ParsedModule>,
-    idx: usize,
-    mut origins: Option<&mut HashMap<String, Option<usize>>>,
-    rx_origin: Option<&str>,
-) -> (usize, usize) {
-    let mut low_pulses = 0;
-    let mut high_pulses = 0;
 
-    let mut pulses: Vec<(String, PulseType, String)> = vec![(
-        "button".to_string(),
-        PulseType::Low,
-        "broadcaster".to_string(),
-    )];
-
-    loop {
-        let mut next_pulses: Vec<(String, PulseType, String)> = Vec::new();
-        if pulses.is_empty() {
-            break;
+    /// Every flip-flop off and every conjunction remembering low pulses
+    fn state(&self) -> State {
+        State {
+            on: vec![false; self.labels.len()],
+            memory: vec![vec![false; self.labels.len()]; self.labels.len()],
         }
+    }
 
-        while let Some((origin, pulse_type, destination)) = pulses.pop() {
-            if let Some(rx_origin) = rx_origin {
-                if pulse_type == PulseType::High && destination == rx_origin {
-                    origins
-                        .as_mut()
-                        .unwrap()
-                        .entry(origin.clone())
-                        .and_modify(|v| *v = Some(idx))
-                        .or_insert(Some(idx));
-                }
-            }
-
-            match pulse_type {
-                PulseType::Low => low_pulses += 1,
-                PulseType::High => high_pulses += 1,
-            }
-            let module = modules_map.get_mut(&destination);
-
-            match (module, pulse_type) {
-                (None, _) => continue,
-                (Some(ParsedModule::Broadcaster { destinations }), _) => {
-                    destinations.iter().for_each(|destination| {
-                        next_pulses.push((
-                            "broadcaster".into(),
-                            PulseType::Low,
-                            destination.clone(),
-                        ));
-                    });
+    /// Push the button, calling `on_pulse` with the sender, the receiver and whether the pulse is
+    /// high for every pulse after the low one from the button to the broadcaster
+    fn press(&self, state: &mut State, mut on_pulse: impl FnMut(usize, usize, bool)) {
+        let broadcaster = self.id("broadcaster");
+        let mut pulses: VecDeque<(usize, usize, bool)> = self
+            .cables
+            .successors(broadcaster)
+            .iter()
+            .map(|&next| (broadcaster, next, false))
+            .collect();
+
+        while let Some((from, to, high)) = pulses.pop_front() {
+            on_pulse(from, to, high);
+            let sent = match self.kinds[to] {
+                Kind::FlipFlop if !high => {
+                    state.on[to] = !state.on[to];
+                    state.on[to]
                 }
-                (Some(ParsedModule::FlipFlop { .. }), PulseType::High) => {
-                    continue;
+                Kind::Conjunction => {
+                    state.memory[to][from] = high;
+                    !self.inputs[to].iter().all(|&input| state.memory[to][input])
                 }
-                (
-                    Some(ParsedModule::FlipFlop {
-                        name,
-                        destinations,
-                        on,
-                    }),
-                    PulseType::Low,
-                ) => {
-                    destinations.iter().for_each(|destination| {
-                        next_pulses.push((
-                            name.clone(),
-                            if *on { PulseType::Low } else { PulseType::High },
-                            destination.clone(),
-                        ));
-                    });
-                    *on = on.not();
-                }
-                (
-                    Some(ParsedModule::Conjunction {
-                        name,
-                        destinations,
-                        pulses_received,
-                    }),
-                    pulse_type,
-                ) => {
-                    pulses_received
-                        .entry(origin)
-                        .and_modify(|t| *t = pulse_type.clone())
-                        .or_insert(pulse_type);
-                    let all_high = pulses_received.values().all(|t| t == &PulseType::High);
-                    destinations.iter().for_each(|destination| {
-                        next_pulses.push((
-                            name.clone(),
-                            if all_high {
-                                PulseType::Low
-                            } else {
-                                PulseType::High
-                            },
-                            destination.clone(),
-                        ));
-                    });
-                }
-            }
+                _ => continue,
+            };
+            pulses.extend(
+                self.cables
+                    .successors(to)
+                    .iter()
+                    .map(|&next| (to, next, sent)),
+            );
         }
-        pulses = next_pulses.clone();
-        next_pulses.clear();
     }
-
-    (low_pulses, high_pulses)
 }
 
-fn initialize_conjunctions_pulses_received(modules_map: &mut HashMap<String, ParsedModule>) {
-    for (name, module) in modules_map.clone().iter() {
-        match module {
-            ParsedModule::Broadcaster { destinations }
-            | ParsedModule::FlipFlop { destinations, .. }
-            | ParsedModule::Conjunction { destinations, .. } => {
-                destinations.iter().for_each(|destination| {
-                    modules_map.entry(destination.clone()).and_modify(|module| {
-                        if let ParsedModule::Conjunction {
-                            pulses_received, ..
-                        } = module
-                        {
-                            pulses_received.insert(name.clone(), PulseType::Low);
-                        }
-                    });
-                });
+fn parse(input: &str) -> Network {
+    let mut labels = Labels::default();
+    let mut cables = Graph::default();
+    let mut kinds = vec![];
+
+    for line in input.trim().lines() {
+        let (kind, name, destinations) = match line.parse().unwrap() {
+            Module::Broadcaster { destinations } => {
+                (Kind::Broadcaster, "broadcaster".to_string(), destinations)
             }
+            Module::FlipFlop { name, destinations } => (Kind::FlipFlop, name, destinations),
+            Module::Conjunction { name, destinations } => (Kind::Conjunction, name, destinations),
+        };
+        let id = labels.id(name);
+        for destination in destinations.split(", ") {
+            cables.add_edge(id, labels.id(destination.to_string()));
         }
+        kinds.resize(labels.len(), Kind::Untyped);
+        kinds[id] = kind;
     }
-}
+    kinds.resize(labels.len(), Kind::Untyped);
 
-fn parse_input(input: &str) -> HashMap<String, ParsedModule> {
-    let mut modules_map: HashMap<String, ParsedModule> = input
-        .trim()
-        .lines()
-        .map(|line| {
-            let module: Module = line.parse().unwrap();
-            let parsed_module: ParsedModule = module.clone().into();
-            match module {
-                Module::Broadcaster { .. } => ("broadcaster".to_string(), parsed_module),
-                Module::FlipFlop { name, .. } => (name, parsed_module),
-                Module::Conjunction { name, .. } => (name, parsed_module),
-            }
-        })
-        .collect();
+    let mut inputs = vec![vec![]; labels.len()];
+    for module in 0..labels.len() {
+        for &destination in cables.successors(module) {
+            inputs[destination].push(module);
+        }
+    }
 
-    initialize_conjunctions_pulses_received(&mut modules_map);
-    modules_map
+    Network {
+        cables,
+        labels,
+        kinds,
+        inputs,
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let mut modules_map = parse_input(input);
-
-    let (low_pulses, high_pulses) = (1..=1000).fold((0, 0), |acc, idx| {
-        let (low_pulses, high_pulses) = press_broadcast(&mut modules_map, idx, None, None);
-        (acc.0 + low_pulses, acc.1 + high_pulses)
-    });
+fn part1(network: &Network) -> usize {
+    let mut state = network.state();
+    // the pulses from the button are all low
+    let (mut low_pulses, mut high_pulses) = (1000, 0);
+
+    for _ in 0..1000 {
+        network.press(&mut state, |_, _, high| {
+            if high {
+                high_pulses += 1;
+            } else {
+                low_pulses += 1;
+            }
+        });
+    }
 
     low_pulses * high_pulses
 }
 
-fn part2(input: &str) -> usize {
-    let mut modules_map = parse_input(input);
-
-    let rx_origin = modules_map
-        .values()
-        .find_map(|module| match module {
-            // module before rx is always going to be a Conjunction
-            ParsedModule::Conjunction {
-                name, destinations, ..
-            } => {
-                if destinations.contains(&"rx".to_string()) {
-                    return Some(name.clone());
+fn part2(network: &Network) -> usize {
+    // rx only receives pulses from a conjunction, sending it a low pulse once all of its inputs
+    // have sent it a high pulse during the same press
+    let rx_origin = network.inputs[network.id("rx")][0];
+
+    // each input of that conjunction ends a separate branch of the network, a counter sending it
+    // a high pulse when it comes back to its initial state, so rx receives a low pulse after the
+    // `lcm` (https://en.wikipedia.org/wiki/Least_common_multiple#Using_the_greatest_common_divisor) of the lengths of the cycles of the branches
+    network.inputs[rx_origin]
+        .iter()
+        .map(|&origin| {
+            let mut branch = vec![false; network.labels.len()];
+            let mut stack = vec![origin];
+            while let Some(module) = stack.pop() {
+                if !branch[module] {
+                    branch[module] = true;
+                    stack.extend(&network.inputs[module]);
                 }
-                None
             }
-            _ => None,
-        })
-        .unwrap();
-
-    let origins: HashMap<String, Option<usize>> = modules_map
-        .values()
-        .filter_map(|module| match module {
-            ParsedModule::Conjunction {
-                name, destinations, ..
-            }
-            | ParsedModule::FlipFlop {
-                name, destinations, ..
-            } => {
-                if destinations.contains(&rx_origin) {
-                    return Some((name.clone(), None));
+            // the modules outside of the branch ignore the pulses
+            let mut counter = network.clone();
+            for (module, kind) in counter.kinds.iter_mut().enumerate() {
+                if !branch[module] {
+                    *kind = Kind::Untyped;
                 }
-                None
             }
-            _ => None,
-        })
-        .collect();
-
-    let mut idx = 0;
-    let origins = Rc::new(RefCell::new(origins));
 
-    loop {
-        idx += 1;
-        let origins_clone = Rc::clone(&origins);
-        press_broadcast(
-            &mut modules_map,
-            idx,
-            Some(&mut origins_clone.borrow_mut()),
-            Some(&rx_origin),
-        );
-
-        if origins.borrow().values().all(|v| v.is_some()) {
-            break;
-        }
-    }
-
-    let value = origins.borrow().values().map(|v| v.unwrap()).product();
-    value
+            let mut state = counter.state();
+            cycle::detect_in_place(&mut state, |state| counter.press(state, |_, _, _| {})).length
+        })
+        .fold(1, |acc, n| acc * (n / n.gcd(acc)))
 }
 
-pub fn main() -> (usize, usize) {
-    let input = include_str!("input.txt");
-    let (part1, part2) = (part1(input), part2(input));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
-
-    (part1, part2)
-}
+aoc_common::solution!(parse -> Network; usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -308,21 +205,39 @@ mod tests {
 
     #[test]
     fn test_example_simple() {
-        let part1 = part1(EXAMPLE_INPUT_SIMPLE);
+        let part1 = part1(&parse(EXAMPLE_INPUT_SIMPLE));
 
         assert_eq!(part1, 32000000);
     }
 
     #[test]
     fn test_example() {
-        let part1 = part1(EXAMPLE_INPUT);
+        let part1 = part1(&parse(EXAMPLE_INPUT));
 
         assert_eq!(part1, 11687500);
     }
 
+    #[test]
+    fn test_example_part2() {
+        // a counter of one bit and one of two bits, both sending a high pulse to hub when they go
+        // back to 0
+        let network = parse(indoc! {"
+            broadcaster -> a, b
+            %a -> x
+            &x -> hub
+            %b -> c
+            %c -> y
+            &y -> hub
+            &hub -> rx
+        "});
+
+        assert_eq!(part2(&network), 4);
+    }
+
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let network = parse(include_str!("input.txt"));
+        let (part1, part2) = (part1(&network), part2(&network));
 
         assert_eq!(part1, 834323022);
         assert_eq!(part2, 225386464601017);
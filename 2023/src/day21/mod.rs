@@ -1,3 +1,5 @@
+use aoc_common::{Solution, Steps};
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 enum Parity {
     Even,
@@ -208,15 +210,30 @@ fn part2(input: &str) -> usize {
     total
 }
 
-pub fn main() -> (usize, usize) {
-    let part1 = part1(include_str!("input.txt"), 64);
-    let part2 = part2(include_str!("input.txt"));
-    println!("part1 {}", part1);
-    println!("part2 {}", part2);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const STEPS: Steps = Steps::Unparsed;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
 
-    (part1, part2)
+    fn part1(input: &&str) -> usize {
+        part1(input, 64)
+    }
+
+    fn part2(input: &&str) -> usize {
+        part2(input)
+    }
 }
 
+aoc_common::register!(Day);
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -246,7 +263,8 @@ mod tests {
 
     #[test]
     fn test_main() {
-        let (part1, part2) = main();
+        let input = include_str!("input.txt");
+        let (part1, part2) = (part1(input, 64), part2(input));
 
         assert_eq!(part1, 3503);
         assert_eq!(part2, 584211423220706);
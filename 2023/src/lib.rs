@@ -20,3 +20,16 @@ pub mod day19;
 pub mod day20;
 pub mod day21;
 pub mod day22;
+
+/// All the days solved for 2023, sorted by day
+pub fn days() -> Vec<&'static aoc_common::Registration> {
+    aoc_common::days(2023)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_every_day_is_registered() {
+        aoc_common::assert_days_registered(2023, env!("CARGO_MANIFEST_DIR"));
+    }
+}
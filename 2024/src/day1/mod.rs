@@ -48,6 +48,8 @@ pub fn part2(input: &str) -> u32 {
         .fold(0, |acc, (value, occ)| acc + value * occ.left * occ.right)
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -1,7 +1,10 @@
 const SIZE: usize = 65;
 const ZEROS_SIZE: usize = 1000;
 
-fn parse(input: &str) -> ([[u8; SIZE]; SIZE], Vec<(usize, usize)>) {
+/// Topographic map and the positions of its trailheads
+type Map = ([[u8; SIZE]; SIZE], Vec<(usize, usize)>);
+
+fn parse(input: &str) -> Map {
     let mut grid = [[b'.'; SIZE]; SIZE];
     let mut zeros = Vec::with_capacity(ZEROS_SIZE);
     input.lines().enumerate().for_each(|(i, line)| {
@@ -52,12 +55,10 @@ fn find_trailhead_score(grid: &[[u8; SIZE]; SIZE], (i, j): (usize, usize)) -> u1
     score
 }
 
-pub fn part1(input: &str) -> u16 {
-    let (grid, zeros) = parse(input);
-
+pub fn part1((grid, zeros): &Map) -> u16 {
     zeros
         .iter()
-        .fold(0, |sum, &zero| sum + find_trailhead_score(&grid, zero))
+        .fold(0, |sum, &zero| sum + find_trailhead_score(grid, zero))
 }
 
 fn find_trailhead_rating(grid: &[[u8; SIZE]; SIZE], (i, j): (usize, usize)) -> u16 {
@@ -90,12 +91,91 @@ fn find_trailhead_rating(grid: &[[u8; SIZE]; SIZE], (i, j): (usize, usize)) -> u
     rating
 }
 
-pub fn part2(input: &str) -> u16 {
-    let (grid, zeros) = parse(input);
-
+pub fn part2((grid, zeros): &Map) -> u16 {
     zeros
         .iter()
-        .fold(0, |sum, &zero| sum + find_trailhead_rating(&grid, zero))
+        .fold(0, |sum, &zero| sum + find_trailhead_rating(grid, zero))
+}
+
+aoc_common::solution!(parse -> Map; u16, u16);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use std::collections::HashSet;
+
+    use aoc_common::Rng;
+
+    use super::SIZE;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = (size + 3).min(SIZE);
+        let mut grid: Vec<Vec<u8>> = (0..side)
+            .map(|_| (0..side).map(|_| b'0' + rng.range(0..=9) as u8).collect())
+            .collect();
+
+        // random digits rarely make trails, walk a few of them
+        for _ in 0..size {
+            let (mut i, mut j) = (
+                rng.range(0..=side as u64 - 1),
+                rng.range(0..=side as u64 - 1),
+            );
+            for elevation in b'0'..=b'9' {
+                grid[i as usize][j as usize] = elevation;
+                match rng.range(0..=3) {
+                    0 if i > 0 => i -= 1,
+                    1 if j > 0 => j -= 1,
+                    2 => i = (i + 1).min(side as u64 - 1),
+                    _ => j = (j + 1).min(side as u64 - 1),
+                }
+            }
+        }
+
+        grid.iter()
+            .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+            .collect()
+    }
+
+    /// Every trail from `(i, j)` at `elevation`, as the position of its end
+    fn reference_trails(
+        grid: &[&[u8]],
+        (i, j): (usize, usize),
+        elevation: u8,
+    ) -> Vec<(usize, usize)> {
+        if grid[i][j] != elevation {
+            return vec![];
+        }
+        if elevation == b'9' {
+            return vec![(i, j)];
+        }
+
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .iter()
+            .filter_map(|&(di, dj)| {
+                let (i, j) = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+                (i < grid.len() && j < grid[i].len()).then_some((i, j))
+            })
+            .flat_map(|next| reference_trails(grid, next, elevation + 1))
+            .collect()
+    }
+
+    fn reference(input: &str, score: fn(Vec<(usize, usize)>) -> usize) -> usize {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+
+        (0..grid.len())
+            .flat_map(|i| (0..grid[i].len()).map(move |j| (i, j)))
+            .map(|start| score(reference_trails(&grid, start, b'0')))
+            .sum()
+    }
+
+    fn reference_part1(input: &str) -> usize {
+        reference(input, |ends| ends.into_iter().collect::<HashSet<_>>().len())
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        reference(input, |ends| ends.len())
+    }
+
+    aoc_common::generator!(generate, reference_part1, reference_part2);
 }
 
 #[cfg(test)]
@@ -116,21 +196,21 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 36);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 36);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 776);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 776);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 81);
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), 81);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 1657);
+        assert_eq!(part2(&parse(include_str!("input.txt"))), 1657);
     }
 }
@@ -47,6 +47,8 @@ pub fn part2(input: &str) -> u64 {
     compute(input, 75)
 }
 
+aoc_common::solution!(u64, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,20 +1,20 @@
+use aoc_toolbox::ArrayGrid;
 use arrayvec::ArrayVec;
 
+/// Largest width and height of the inputs
 const SIZE: usize = 140;
 
+type Grid = ArrayGrid<u8, SIZE>;
+
 pub fn part1(input: &str) -> u32 {
-    let mut grid = [[b'0'; SIZE]; SIZE];
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-        });
-    });
+    let grid = Grid::parse(input, b'0', |_, c| c).unwrap();
+    let (height, width) = (grid.height(), grid.width());
     let mut visited = [[false; SIZE]; SIZE];
     let mut total = 0;
     let mut stack = ArrayVec::<(usize, usize), { 3 * SIZE }>::new();
 
-    for i in 0..SIZE {
-        for j in 0..SIZE {
+    for i in 0..height {
+        for j in 0..width {
             if visited[i][j] || grid[i][j] == b'0' {
                 continue;
             }
@@ -32,10 +32,10 @@ pub fn part1(input: &str) -> u32 {
                 area += 1;
                 visited[i][j] = true;
                 // on the edge
-                if i == SIZE - 1 || grid[i + 1][j] == b'0' {
+                if i == height - 1 || grid[i + 1][j] == b'0' {
                     perimeter += 1;
                 }
-                if j == SIZE - 1 || grid[i][j + 1] == b'0' {
+                if j == width - 1 || grid[i][j + 1] == b'0' {
                     perimeter += 1;
                 }
                 if i == 0 {
@@ -60,14 +60,14 @@ pub fn part1(input: &str) -> u32 {
                     }
                 }
 
-                if i < SIZE - 1 && grid[i + 1][j] != b'0' {
+                if i < height - 1 && grid[i + 1][j] != b'0' {
                     if grid[i + 1][j] != c_char {
                         perimeter += 1;
                     } else {
                         stack.push((i + 1, j));
                     }
                 }
-                if j < SIZE - 1 && grid[i][j + 1] != b'0' {
+                if j < width - 1 && grid[i][j + 1] != b'0' {
                     if grid[i][j + 1] != c_char {
                         perimeter += 1;
                     } else {
@@ -84,18 +84,14 @@ pub fn part1(input: &str) -> u32 {
 }
 
 pub fn part2(input: &str) -> u32 {
-    let mut grid = [[b'0'; SIZE]; SIZE];
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-        });
-    });
+    let grid = Grid::parse(input, b'0', |_, c| c).unwrap();
+    let (height, width) = (grid.height(), grid.width());
     let mut visited = [[false; SIZE]; SIZE];
     let mut total = 0;
     let mut stack = ArrayVec::<(usize, usize), { 3 * SIZE }>::new();
 
-    for i in 0..SIZE {
-        for j in 0..SIZE {
+    for i in 0..height {
+        for j in 0..width {
             if visited[i][j] || grid[i][j] == b'0' {
                 continue;
             }
@@ -138,7 +134,7 @@ pub fn part2(input: &str) -> u32 {
 
                 // test if top-right corner
                 if i > 0
-                    && j < SIZE - 1
+                    && j < width - 1
                     && ((grid[i - 1][j + 1] != c_char
                         && grid[i - 1][j + 1] != b'0'
                         && grid[i - 1][j] == c_char
@@ -148,21 +144,21 @@ pub fn part2(input: &str) -> u32 {
                     corners += 1;
                 }
                 // test if top-right corner when i==0
-                if i == 0 && j < SIZE - 1 && (grid[i][j + 1] != c_char || grid[i][j + 1] == b'0') {
+                if i == 0 && j < width - 1 && (grid[i][j + 1] != c_char || grid[i][j + 1] == b'0') {
                     corners += 1;
                 }
-                // test if top-right corner when j==SIZE-1
-                if j == SIZE - 1 && i > 0 && grid[i - 1][j] != c_char {
+                // test if top-right corner when j==width-1
+                if j == width - 1 && i > 0 && grid[i - 1][j] != c_char {
                     corners += 1;
                 }
-                // test if top-right corner when i==0 and j==SIZE-1
-                if i == 0 && j == SIZE - 1 {
+                // test if top-right corner when i==0 and j==width-1
+                if i == 0 && j == width - 1 {
                     corners += 1;
                 }
 
                 // test if bottom-right corner
-                if i < SIZE - 1
-                    && j < SIZE - 1
+                if i < height - 1
+                    && j < width - 1
                     && ((grid[i + 1][j + 1] != c_char
                         && grid[i + 1][j + 1] != b'0'
                         && (grid[i + 1][j] == c_char || grid[i + 1][j] == b'0')
@@ -171,27 +167,27 @@ pub fn part2(input: &str) -> u32 {
                 {
                     corners += 1;
                 }
-                // test if bottom-right corner when i==SIZE-1
-                if i == SIZE - 1
-                    && j < SIZE - 1
+                // test if bottom-right corner when i==height-1
+                if i == height - 1
+                    && j < width - 1
                     && (grid[i][j + 1] != c_char || grid[i][j + 1] == b'0')
                 {
                     corners += 1;
                 }
-                // test if bottom-right corner when j==SIZE-1
-                if j == SIZE - 1
-                    && i < SIZE - 1
+                // test if bottom-right corner when j==width-1
+                if j == width - 1
+                    && i < height - 1
                     && (grid[i + 1][j] != c_char || grid[i + 1][j] == b'0')
                 {
                     corners += 1;
                 }
-                // test if bottom-right corner when i==SIZE-1 and j==SIZE-1
-                if i == SIZE - 1 && j == SIZE - 1 {
+                // test if bottom-right corner when i==height-1 and j==width-1
+                if i == height - 1 && j == width - 1 {
                     corners += 1;
                 }
 
                 // test if bottom-left corner
-                if i < SIZE - 1
+                if i < height - 1
                     && j > 0
                     && ((grid[i + 1][j - 1] != c_char
                         && grid[i + 1][j - 1] != b'0'
@@ -201,16 +197,17 @@ pub fn part2(input: &str) -> u32 {
                 {
                     corners += 1;
                 }
-                // test if bottom-left corner when i==SIZE-1
-                if i == SIZE - 1 && j > 0 && grid[i][j - 1] != c_char {
+                // test if bottom-left corner when i==height-1
+                if i == height - 1 && j > 0 && grid[i][j - 1] != c_char {
                     corners += 1;
                 }
                 // test if bottom-left corner when j==0
-                if j == 0 && i < SIZE - 1 && (grid[i + 1][j] != c_char || grid[i + 1][j] == b'0') {
+                if j == 0 && i < height - 1 && (grid[i + 1][j] != c_char || grid[i + 1][j] == b'0')
+                {
                     corners += 1;
                 }
-                // test if bottom-left corner when i==SIZE-1 and j==0
-                if i == SIZE - 1 && j == 0 {
+                // test if bottom-left corner when i==height-1 and j==0
+                if i == height - 1 && j == 0 {
                     corners += 1;
                 }
 
@@ -221,10 +218,10 @@ pub fn part2(input: &str) -> u32 {
                 if j > 0 && grid[i][j - 1] == c_char {
                     stack.push((i, j - 1));
                 }
-                if i < SIZE - 1 && grid[i + 1][j] != b'0' && grid[i + 1][j] == c_char {
+                if i < height - 1 && grid[i + 1][j] != b'0' && grid[i + 1][j] == c_char {
                     stack.push((i + 1, j));
                 }
-                if j < SIZE - 1 && grid[i][j + 1] != b'0' && grid[i][j + 1] == c_char {
+                if j < width - 1 && grid[i][j + 1] != b'0' && grid[i][j + 1] == c_char {
                     stack.push((i, j + 1));
                 }
             }
@@ -236,6 +233,106 @@ pub fn part2(input: &str) -> u32 {
     total
 }
 
+aoc_common::solution!(u32, u32);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use std::collections::HashSet;
+
+    use aoc_common::Rng;
+
+    use super::SIZE;
+
+    const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = (size + 2).min(SIZE);
+        let mut grid = vec![vec![b'A'; side]; side];
+        for i in 0..side {
+            for j in 0..side {
+                // copying a neighbour grows regions of every shape, holes included
+                grid[i][j] = match rng.range(0..=2) {
+                    0 if i > 0 => grid[i - 1][j],
+                    1 if j > 0 => grid[i][j - 1],
+                    _ => b'A' + rng.range(0..=4) as u8,
+                };
+            }
+        }
+
+        grid.iter()
+            .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+            .collect()
+    }
+
+    /// Regions of the garden, as the positions of their plots
+    fn regions(input: &str) -> Vec<HashSet<(isize, isize)>> {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let plant = |(i, j): (isize, isize)| grid.get(i as usize)?.get(j as usize).copied();
+
+        let mut regions: Vec<HashSet<(isize, isize)>> = vec![];
+        for i in 0..grid.len() as isize {
+            for j in 0..grid[i as usize].len() as isize {
+                if regions.iter().any(|region| region.contains(&(i, j))) {
+                    continue;
+                }
+                let mut region = HashSet::new();
+                let mut stack = vec![(i, j)];
+                while let Some(position) = stack.pop() {
+                    if region.insert(position) {
+                        stack.extend(
+                            DIRECTIONS
+                                .iter()
+                                .map(|&(di, dj)| (position.0 + di, position.1 + dj))
+                                .filter(|&next| plant(next) == plant(position)),
+                        );
+                    }
+                }
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    /// Fences around `region`, as a plot and the direction of the fence from it
+    fn fences(
+        region: &HashSet<(isize, isize)>,
+    ) -> impl Iterator<Item = ((isize, isize), (isize, isize))> + '_ {
+        region.iter().flat_map(move |&(i, j)| {
+            DIRECTIONS
+                .into_iter()
+                .filter(move |&(di, dj)| !region.contains(&(i + di, j + dj)))
+                .map(move |direction| ((i, j), direction))
+        })
+    }
+
+    fn reference_part1(input: &str) -> usize {
+        regions(input)
+            .iter()
+            .map(|region| region.len() * fences(region).count())
+            .sum()
+    }
+
+    /// Price with the number of sides, a side being counted at its fence with no fence of the
+    /// same side next to it
+    fn reference_part2(input: &str) -> usize {
+        regions(input)
+            .iter()
+            .map(|region| {
+                let sides = fences(region)
+                    .filter(|&((i, j), (di, dj))| {
+                        let along = (i + dj, j + di);
+                        !(region.contains(&along)
+                            && !region.contains(&(along.0 + di, along.1 + dj)))
+                    })
+                    .count();
+                region.len() * sides
+            })
+            .sum()
+    }
+
+    aoc_common::generator!(generate, reference_part1, reference_part2);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,7 +109,8 @@ impl Claw {
     }
 }
 
-fn inner(input: &str, offset: i64) -> i64 {
+/// Cost of the prizes won by pressing each button between 0 and `max_presses` times
+fn inner(input: &str, offset: i64, max_presses: i64) -> i64 {
     input.trim_ascii_end().split("\n\n").fold(0, |acc, claw| {
         // let mut claw: SlowClaw = claw.parse().unwrap();
         let mut claw = Claw::parse(claw);
@@ -119,7 +120,11 @@ fn inner(input: &str, offset: i64) -> i64 {
         let n = (claw.px * claw.by - claw.py * claw.bx) / (claw.ax * claw.by - claw.ay * claw.bx);
         let m = (claw.px * claw.ay - claw.py * claw.ax) / (claw.bx * claw.ay - claw.by * claw.ax);
 
-        if n * claw.ax + m * claw.bx == claw.px && n * claw.ay + m * claw.by == claw.py {
+        if (0..=max_presses).contains(&n)
+            && (0..=max_presses).contains(&m)
+            && n * claw.ax + m * claw.bx == claw.px
+            && n * claw.ay + m * claw.by == claw.py
+        {
             acc + n * 3 + m
         } else {
             acc
@@ -128,11 +133,74 @@ fn inner(input: &str, offset: i64) -> i64 {
 }
 
 pub fn part1(input: &str) -> i64 {
-    inner(input, 0)
+    inner(input, 0, 100)
 }
 
 pub fn part2(input: &str) -> i64 {
-    inner(input, 10_000_000_000_000)
+    inner(input, 10_000_000_000_000, i64::MAX)
+}
+
+aoc_common::solution!(i64, i64);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use aoc_common::Rng;
+
+    use super::SlowClaw;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let claws: Vec<String> = (0..size)
+            .map(|_| {
+                let mut button = || (rng.range(10..=99) as i64, rng.range(10..=99) as i64);
+                let ((ax, ay), (bx, by)) = loop {
+                    let (a, b) = (button(), button());
+                    // the buttons of the inputs are never collinear
+                    if a.0 * b.1 != a.1 * b.0 {
+                        break (a, b);
+                    }
+                };
+                let (px, py) = if rng.chance(1, 2) {
+                    let (n, m) = (rng.range(0..=100) as i64, rng.range(0..=100) as i64);
+                    (n * ax + m * bx, n * ay + m * by)
+                } else {
+                    (rng.range(0..=20_000) as i64, rng.range(0..=20_000) as i64)
+                };
+
+                SlowClaw {
+                    ax,
+                    ay,
+                    bx,
+                    by,
+                    px,
+                    py,
+                }
+                .to_string()
+            })
+            .collect();
+
+        claws.join("\n\n") + "\n"
+    }
+
+    /// Cheapest way to win each prize, trying every number of presses up to 100
+    fn reference_part1(input: &str) -> i64 {
+        input
+            .trim_end()
+            .split("\n\n")
+            .map(|claw| {
+                let claw: SlowClaw = claw.parse().unwrap();
+                (0..=100)
+                    .flat_map(|n| (0..=100).map(move |m| (n, m)))
+                    .filter(|&(n, m)| {
+                        n * claw.ax + m * claw.bx == claw.px && n * claw.ay + m * claw.by == claw.py
+                    })
+                    .map(|(n, m)| n * 3 + m)
+                    .min()
+                    .unwrap_or(0)
+            })
+            .sum()
+    }
+
+    aoc_common::generator!(generate, reference_part1);
 }
 
 #[cfg(test)]
@@ -163,6 +231,22 @@ mod tests {
         assert_eq!(part1(EXAMPLE_INPUT), 480);
     }
 
+    /// Prizes only won by pressing a button more than 100 times, or a negative number of times
+    #[test]
+    fn test_part1_press_bound() {
+        let input = indoc! {"
+            Button A: X+10, Y+11
+            Button B: X+13, Y+7
+            Prize: X=1500, Y=1650
+
+            Button A: X+10, Y+11
+            Button B: X+13, Y+7
+            Prize: X=37, Y=48
+        "};
+
+        assert_eq!(part1(input), 0);
+    }
+
     #[test]
     fn test_part1() {
         assert_eq!(part1(include_str!("input.txt")), 37686);
@@ -1,3 +1,4 @@
+use aoc_toolbox::cycle;
 use arrayvec::ArrayVec;
 use parse_display::{Display, FromStr};
 
@@ -83,31 +84,29 @@ impl Robot {
     }
 }
 
-// sizes for the example input
-// const WIDTH = 11;
-// const HEIGHT = 7;
+// sizes of the room for the real inputs, the example one is 11x7
 const WIDTH: i32 = 101;
 const HEIGHT: i32 = 103;
-const HALF_W: i32 = WIDTH / 2;
-const HALF_H: i32 = HEIGHT / 2;
 
 const ROBOTS_SIZE: usize = 500;
 
-pub fn part1(input: &str) -> u32 {
+/// Product of the number of robots in each quadrant of a `width` × `height` room after 100 seconds
+fn safety_factor(input: &str, width: i32, height: i32) -> u32 {
     let robots: ArrayVec<Robot, ROBOTS_SIZE> = input.lines().map(Robot::parse).collect();
+    let (half_w, half_h) = (width / 2, height / 2);
     let mut quadrants: [u32; 4] = [0; 4];
 
     robots.iter().for_each(|robot| {
-        let x = (robot.px + 100 * robot.vx).rem_euclid(WIDTH);
-        let y = (robot.py + 100 * robot.vy).rem_euclid(HEIGHT);
+        let x = (robot.px + 100 * robot.vx).rem_euclid(width);
+        let y = (robot.py + 100 * robot.vy).rem_euclid(height);
 
-        if x < HALF_W && y < HALF_H {
+        if x < half_w && y < half_h {
             quadrants[0] += 1;
-        } else if x > HALF_W && y < HALF_H {
+        } else if x > half_w && y < half_h {
             quadrants[1] += 1;
-        } else if x < HALF_W && y > HALF_H {
+        } else if x < half_w && y > half_h {
             quadrants[2] += 1;
-        } else if x > HALF_W && y > HALF_H {
+        } else if x > half_w && y > half_h {
             quadrants[3] += 1;
         }
     });
@@ -115,10 +114,12 @@ pub fn part1(input: &str) -> u32 {
     quadrants.iter().product()
 }
 
+pub fn part1(input: &str) -> u32 {
+    safety_factor(input, WIDTH, HEIGHT)
+}
+
 // no need to use all 500 robots since we are checking the variance
-const SAMPLED_SIZE: usize = 128;
-const VARIANCE_THRESHOLD: u32 = 2300; // arbitrary threshold
-const WIDTH_INVERSE_IN_CHINESE_REMAINDER_THEOREM_WITH_HEIGHT: u32 = 51;
+const SAMPLED_SIZE: usize = 256;
 
 fn fake_variance(values: &[u32]) -> u32 {
     let mean = values.iter().sum::<u32>() / SAMPLED_SIZE as u32;
@@ -126,43 +127,171 @@ fn fake_variance(values: &[u32]) -> u32 {
     variance
 }
 
+/// Second at which the robots at the positions and velocities of `robots` along one axis of
+/// `size` are the least spread along it, and the number of seconds before they come back
+fn least_spread(robots: ArrayVec<(i32, i32), SAMPLED_SIZE>, size: i32) -> (usize, usize) {
+    let (cycle, states) = cycle::detect(robots, |robots| {
+        robots
+            .iter()
+            .map(|&(position, velocity)| ((position + velocity).rem_euclid(size), velocity))
+            .collect()
+    });
+
+    // moving around the room, the robots come back to where they started
+    let seconds = (0..cycle.length)
+        .min_by_key(|&seconds| {
+            let positions: ArrayVec<u32, SAMPLED_SIZE> = states[seconds]
+                .iter()
+                .map(|&(position, _)| position as u32)
+                .collect();
+            fake_variance(&positions)
+        })
+        .unwrap();
+    (seconds, cycle.length)
+}
+
 pub fn part2(input: &str) -> u32 {
-    let mut robots: ArrayVec<Robot, SAMPLED_SIZE> =
+    let robots: ArrayVec<Robot, SAMPLED_SIZE> =
         input.lines().take(SAMPLED_SIZE).map(Robot::parse).collect();
 
-    let mut bx = 0;
-    let mut by = 0;
+    // the robots are the closest together along both axes when drawing the picture
+    let (bx, width) = least_spread(robots.iter().map(|r| (r.px, r.vx)).collect(), WIDTH);
+    let (by, height) = least_spread(robots.iter().map(|r| (r.py, r.vy)).collect(), HEIGHT);
 
-    for seconds in 1..=(HEIGHT as usize) {
-        for robot in robots.iter_mut() {
-            robot.px = (robot.px + robot.vx).rem_euclid(WIDTH);
-            robot.py = (robot.py + robot.vy).rem_euclid(HEIGHT);
-        }
+    // first second at which both happen, with the Chinese remainder theorem
+    (0..height)
+        .map(|k| bx + k * width)
+        .find(|seconds| seconds % height == by)
+        .unwrap() as u32
+}
+
+aoc_common::solution!(u32, u32);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use std::collections::HashSet;
+
+    use aoc_common::Rng;
+
+    use super::{SlowRobot, HEIGHT, WIDTH};
 
-        let x_positions: ArrayVec<u32, SAMPLED_SIZE> =
-            robots.iter().map(|robot| robot.px as u32).collect();
-        if fake_variance(&x_positions) < VARIANCE_THRESHOLD {
-            bx = seconds;
-            if by != 0 {
-                break;
-            }
+    /// Width and height of the frame around the picture
+    const FRAME: (i32, i32) = (31, 33);
+
+    /// Robots drawing a framed tree at a random second, among up to `size` robots moving at random
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let seconds = rng.range(1..=(WIDTH * HEIGHT - 1) as u64) as i32;
+        let (left, top) = (
+            rng.range(0..=(WIDTH - FRAME.0) as u64) as i32,
+            rng.range(0..=(HEIGHT - FRAME.1) as u64) as i32,
+        );
+
+        let mut picture: Vec<(i32, i32)> = (0..FRAME.0)
+            .flat_map(|x| [(x, 0), (x, FRAME.1 - 1)])
+            .chain((1..FRAME.1 - 1).flat_map(|y| [(0, y), (FRAME.0 - 1, y)]))
+            .collect();
+        // a tree growing one robot wider on each side every fourth row, on a trunk
+        let center = FRAME.0 / 2;
+        for y in 2..FRAME.1 - 5 {
+            let half = (y - 2) / 4;
+            picture.extend((center - half..=center + half).map(|x| (x, y)));
+        }
+        for y in FRAME.1 - 5..FRAME.1 - 2 {
+            picture.extend((center - 1..=center + 1).map(|x| (x, y)));
+        }
+        // most robots of the inputs draw the picture
+        let noise = size.min(picture.len() / 3);
+        let mut positions: Vec<(i32, i32)> = picture
+            .into_iter()
+            .map(|(x, y)| (left + x, top + y))
+            .collect();
+        for _ in 0..noise {
+            positions.push((
+                rng.range(0..=WIDTH as u64 - 1) as i32,
+                rng.range(0..=HEIGHT as u64 - 1) as i32,
+            ));
         }
 
-        let y_positions: ArrayVec<u32, SAMPLED_SIZE> =
-            robots.iter().map(|robot| robot.py as u32).collect();
-        if fake_variance(&y_positions) < VARIANCE_THRESHOLD {
-            by = seconds;
-            if bx != 0 {
-                break;
-            }
+        let mut robots: Vec<SlowRobot> = positions
+            .into_iter()
+            .map(|(x, y)| {
+                let mut velocity = |size: i32| {
+                    let speed = rng.range(1..=size as u64 - 1) as i32;
+                    if rng.chance(1, 2) {
+                        speed
+                    } else {
+                        -speed
+                    }
+                };
+                let (vx, vy) = (velocity(WIDTH), velocity(HEIGHT));
+                SlowRobot {
+                    px: (x - seconds * vx).rem_euclid(WIDTH),
+                    py: (y - seconds * vy).rem_euclid(HEIGHT),
+                    vx,
+                    vy,
+                }
+            })
+            .collect();
+        for i in (1..robots.len()).rev() {
+            robots.swap(i, rng.range(0..=i as u64) as usize);
         }
+
+        robots.iter().map(|robot| format!("{}\n", robot)).collect()
+    }
+
+    fn robots(input: &str) -> Vec<SlowRobot> {
+        input.lines().map(|line| line.parse().unwrap()).collect()
+    }
+
+    /// Positions of the robots after `seconds`
+    fn positions(robots: &[SlowRobot], seconds: i32) -> Vec<(i32, i32)> {
+        robots
+            .iter()
+            .map(|robot| {
+                (
+                    (robot.px + seconds * robot.vx).rem_euclid(WIDTH),
+                    (robot.py + seconds * robot.vy).rem_euclid(HEIGHT),
+                )
+            })
+            .collect()
+    }
+
+    fn reference_part1(input: &str) -> usize {
+        let (half_w, half_h) = (WIDTH / 2, HEIGHT / 2);
+        let positions = positions(&robots(input), 100);
+
+        [(false, false), (true, false), (false, true), (true, true)]
+            .iter()
+            .map(|&(right, bottom)| {
+                positions
+                    .iter()
+                    .filter(|&&(x, y)| {
+                        x != half_w
+                            && y != half_h
+                            && (x > half_w) == right
+                            && (y > half_h) == bottom
+                    })
+                    .count()
+            })
+            .product()
+    }
+
+    /// First second at which the robots draw the frame of the picture, a row of robots as wide
+    /// as the frame
+    fn reference_part2(input: &str) -> i32 {
+        let robots = robots(input);
+        (0..WIDTH * HEIGHT)
+            .find(|&seconds| {
+                let positions: HashSet<(i32, i32)> =
+                    positions(&robots, seconds).into_iter().collect();
+                positions
+                    .iter()
+                    .any(|&(x, y)| (0..FRAME.0).all(|dx| positions.contains(&(x + dx, y))))
+            })
+            .unwrap()
     }
 
-    // see https://www.reddit.com/r/adventofcode/comments/1he0asr/2024_day_14_part_2_why_have_fun_with_image/
-    bx as u32
-        + (WIDTH_INVERSE_IN_CHINESE_REMAINDER_THEOREM_WITH_HEIGHT * (by as u32 - bx as u32))
-            .rem_euclid(HEIGHT as u32)
-            * WIDTH as u32
+    aoc_common::generator!(generate, reference_part1, reference_part2);
 }
 
 #[cfg(test)]
@@ -186,9 +315,8 @@ mod tests {
 "};
 
     #[test]
-    #[ignore]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 12);
+        assert_eq!(safety_factor(EXAMPLE_INPUT, 11, 7), 12);
     }
 
     #[test]
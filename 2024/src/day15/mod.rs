@@ -275,6 +275,8 @@ pub fn part2(input: &str) -> u32 {
     compute_gps::<2>(&grid)
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +166,8 @@ pub fn part2(input: &str) -> u64 {
     find_for_all(&program, &program, 0).unwrap()
 }
 
+aoc_common::solution!(String, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
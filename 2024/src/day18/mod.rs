@@ -1,39 +1,19 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
+use aoc_toolbox::{search::bfs, ArrayGrid, Direction4, Point};
 use itertools::Itertools;
 
-const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-#[derive(Clone, Eq, PartialEq)]
-struct State {
-    cost: usize,
-    x: usize,
-    y: usize,
-}
-
-// The priority queue depends on `Ord`.
-// Explicitly implement the trait so the queue becomes a min-heap
-// instead of a max-heap.
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Notice that the we flip the ordering on costs, making sure that the queue becomes a min-heap
-        other.cost.cmp(&self.cost)
-    }
-}
+/// Width and height of the memory space of the real inputs, the example one is 7x7
+const SIZE: usize = 71;
 
-// `PartialOrd` needs to be implemented as well.
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+type Grid = ArrayGrid<u8, SIZE>;
 
-const SIZE: usize = 71;
 const BYTES_SIZE: usize = 4000;
 
+/// Empty memory space and the positions of the bytes in their falling order
+type Memory = (Grid, Vec<(usize, usize)>);
+
+/// Memory space of `size` × `size` with the bytes of `input` falling into it
 #[inline(always)]
-fn parse(input: &str) -> ([[u8; SIZE]; SIZE], Vec<(usize, usize)>) {
-    let grid = [[b'.'; SIZE]; SIZE];
+fn memory(input: &str, size: usize) -> Memory {
     let mut bytes: Vec<(usize, usize)> = Vec::with_capacity(BYTES_SIZE);
     bytes.extend(input.lines().map(|l| {
         l.split(",")
@@ -42,75 +22,55 @@ fn parse(input: &str) -> ([[u8; SIZE]; SIZE], Vec<(usize, usize)>) {
             .unwrap()
     }));
 
-    (grid, bytes)
+    (Grid::new(size, size, b'.'), bytes)
 }
 
-#[inline(always)]
-fn find_path(grid: &[[u8; SIZE]; SIZE]) -> Option<usize> {
-    let mut p_queue = BinaryHeap::with_capacity(SIZE * SIZE);
-    let mut visited = [[false; SIZE]; SIZE];
-    p_queue.push(State {
-        cost: 0,
-        x: 0,
-        y: 0,
-    });
-
-    while let Some(cur) = p_queue.pop() {
-        if cur.x == SIZE - 1 && cur.y == SIZE - 1 {
-            return Some(cur.cost);
-        }
-        if visited[cur.x][cur.y] {
-            continue;
-        }
-        visited[cur.x][cur.y] = true;
-
-        for (dx, dy) in &DIRECTIONS {
-            let nx = cur.x as i32 + dx;
-            let ny = cur.y as i32 + dy;
-            if nx < 0 || ny < 0 || nx >= SIZE as i32 || ny >= SIZE as i32 {
-                continue;
-            }
-            let nx = nx as usize;
-            let ny = ny as usize;
-            if visited[nx][ny] {
-                continue;
-            }
-            if grid[nx][ny] == b'#' {
-                continue;
-            }
-            p_queue.push(State {
-                cost: cur.cost + 1,
-                x: nx,
-                y: ny,
-            });
-        }
-    }
+fn parse(input: &str) -> Memory {
+    memory(input, SIZE)
+}
 
-    None
+#[inline(always)]
+fn find_path(grid: &Grid) -> Option<usize> {
+    let (height, width) = (grid.height(), grid.width());
+    let successors = |&position: &Point<usize>| {
+        Direction4::ALL.into_iter().filter_map(move |direction| {
+            position
+                .checked_step(direction, width, height)
+                .filter(|next| grid[next.y][next.x] != b'#')
+        })
+    };
+
+    bfs(Point::new(0, 0), successors, |position| {
+        *position == Point::new(width - 1, height - 1)
+    })
+    .map(|path| path.cost)
 }
 
-pub fn part1(input: &str) -> u32 {
-    let (mut grid, bytes) = parse(input);
+/// Minimum number of steps to reach the exit once the first `fallen` bytes have fallen
+fn steps_after((grid, bytes): &Memory, fallen: usize) -> u32 {
+    let mut grid = *grid;
 
-    for (x, y) in &bytes[..1024] {
-        grid[*x][*y] = b'#';
+    for (x, y) in bytes.iter().take(fallen) {
+        grid[*y][*x] = b'#';
     }
 
     find_path(&grid).unwrap() as u32
 }
 
-pub fn part2(input: &str) -> String {
-    let (grid, bytes) = parse(input);
+pub fn part1(memory: &Memory) -> u32 {
+    steps_after(memory, 1024)
+}
 
+pub fn part2((grid, bytes): &Memory) -> String {
     let mut left = 0;
     let mut right = bytes.len();
 
     while left < right {
         let mid = (left + right) / 2;
-        let mut grid = grid;
+        let mut grid = *grid;
 
         for (x, y) in &bytes[..mid] {
-            grid[*x][*y] = b'#';
+            grid[*y][*x] = b'#';
         }
 
         if find_path(&grid).is_none() {
@@ -124,6 +84,8 @@ pub fn part2(input: &str) -> String {
     format!("{},{}", x, y)
 }
 
+aoc_common::solution!(parse -> Memory; u32, String);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,24 +120,28 @@ mod tests {
 "};
 
     #[test]
-    #[ignore]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 22);
+        // only 12 bytes have fallen in the example
+        assert_eq!(steps_after(&memory(EXAMPLE_INPUT, 7), 12), 22);
+    }
+
+    #[test]
+    fn test_part1_without_bytes() {
+        assert_eq!(part1(&parse("")), 140);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 348);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 348);
     }
 
     #[test]
-    #[ignore]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), "6,1");
+        assert_eq!(part2(&memory(EXAMPLE_INPUT, 7)), "6,1");
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), "54,44");
+        assert_eq!(part2(&parse(include_str!("input.txt"))), "54,44");
     }
 }
@@ -31,12 +31,11 @@ fn possible(towels: &[&str], cache: &mut FxHashMap<String, bool>, design: &str)
     is_possible
 }
 
-pub fn part1(input: &str) -> u32 {
-    let (towels, designs) = parse(input);
+pub fn part1((towels, designs): &(Vec<&str>, Vec<&str>)) -> u32 {
     let mut cache = FxHashMap::default();
 
     designs.iter().fold(0, |acc, design| {
-        if possible(&towels, &mut cache, design) {
+        if possible(towels, &mut cache, design) {
             acc + 1
         } else {
             acc
@@ -64,15 +63,16 @@ fn combinations(towels: &[&str], cache: &mut FxHashMap<String, u64>, design: &st
     count
 }
 
-pub fn part2(input: &str) -> u64 {
-    let (towels, designs) = parse(input);
+pub fn part2((towels, designs): &(Vec<&str>, Vec<&str>)) -> u64 {
     let mut cache = FxHashMap::default();
 
     designs.iter().fold(0, |acc, design| {
-        acc + combinations(&towels, &mut cache, design)
+        acc + combinations(towels, &mut cache, design)
     })
 }
 
+aoc_common::solution!(parse -> (Vec<&'a str>, Vec<&'a str>); u32, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,21 +93,21 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 6);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 6);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 293);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 293);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 16);
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), 16);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 623924810770264);
+        assert_eq!(part2(&parse(include_str!("input.txt"))), 623924810770264);
     }
 }
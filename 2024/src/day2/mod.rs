@@ -70,6 +70,8 @@ pub fn part2(input: &str) -> u32 {
         })
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -1,7 +1,12 @@
+use aoc_toolbox::ArrayGrid;
+
+/// Largest width and height of the inputs
 const SIZE: usize = 141;
 
+type Grid = ArrayGrid<u8, SIZE>;
+
 #[derive(Debug, Clone, Copy)]
-struct Item {
+pub struct Item {
     pos: (usize, usize),
     distance_to_end: usize,
 }
@@ -9,24 +14,25 @@ struct Item {
 const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
 const CHEATS: [(i32, i32); 4] = [(2, 0), (-2, 0), (0, 2), (0, -2)];
 
-fn parse(input: &str) -> ([[u8; 141]; 141], Vec<Item>, [[usize; 141]; 141]) {
-    let mut grid = [[b'#'; SIZE]; SIZE];
+/// Racetrack, the positions along its path and the distance from each of them to the end
+type Track = (Grid, Vec<Item>, [[usize; SIZE]; SIZE]);
+
+fn parse(input: &str) -> Track {
     let mut start = (0, 0);
     let mut end = (0, 0);
+    let grid = Grid::parse(input, b'#', |position, b| {
+        if b == b'S' {
+            start = position;
+        } else if b == b'E' {
+            end = position;
+        }
+        b
+    })
+    .unwrap();
+    let (height, width) = (grid.height(), grid.width());
 
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.as_bytes().iter().enumerate().for_each(|(j, &b)| {
-            grid[i][j] = b;
-            if b == b'S' {
-                start = (i, j);
-            } else if b == b'E' {
-                end = (i, j);
-            }
-        });
-    });
-
-    let mut path_items = Vec::with_capacity(SIZE * SIZE);
-    let mut stack = Vec::with_capacity(SIZE * SIZE);
+    let mut path_items = Vec::with_capacity(width * height);
+    let mut stack = Vec::with_capacity(width * height);
     stack.push(start);
 
     while let Some(pos) = stack.pop() {
@@ -46,7 +52,7 @@ fn parse(input: &str) -> ([[u8; 141]; 141], Vec<Item>, [[usize; 141]; 141]) {
         for (dx, dy) in &DIRECTIONS {
             let nx = pos.0 as i32 + dx;
             let ny = pos.1 as i32 + dy;
-            if nx < 0 || ny < 0 || nx >= SIZE as i32 || ny >= SIZE as i32 {
+            if nx < 0 || ny < 0 || nx >= height as i32 || ny >= width as i32 {
                 continue;
             }
             let nx = nx as usize;
@@ -73,19 +79,17 @@ fn parse(input: &str) -> ([[u8; 141]; 141], Vec<Item>, [[usize; 141]; 141]) {
     (grid, path_items, d_grid)
 }
 
-pub fn part1(input: &str) -> u32 {
-    let (grid, path_items, d_grid) = parse(input);
-
+pub fn part1((grid, path_items, d_grid): &Track) -> u32 {
     let mut valid_cheats = 0;
 
-    for item in &path_items {
+    for item in path_items {
         for (dx, dy) in &CHEATS {
             let nx = item.pos.0 as i32 + dx;
             let ny = item.pos.1 as i32 + dy;
             if nx < 0
                 || ny < 0
-                || nx >= SIZE as i32
-                || ny >= SIZE as i32
+                || nx >= grid.height() as i32
+                || ny >= grid.width() as i32
                 || grid[nx as usize][ny as usize] == b'#'
             {
                 continue;
@@ -105,12 +109,10 @@ pub fn part1(input: &str) -> u32 {
     valid_cheats
 }
 
-pub fn part2(input: &str) -> u32 {
-    let (grid, path_items, d_grid) = parse(input);
-
+pub fn part2((grid, path_items, d_grid): &Track) -> u32 {
     let mut valid_cheats = 0;
 
-    for item in &path_items {
+    for item in path_items {
         for dx in -20i32..=20 {
             for dy in -20i32..=20 {
                 if dx.abs() + dy.abs() > 20 {
@@ -120,8 +122,8 @@ pub fn part2(input: &str) -> u32 {
                 let ny = item.pos.1 as i32 + dy;
                 if nx < 0
                     || ny < 0
-                    || nx >= SIZE as i32
-                    || ny >= SIZE as i32
+                    || nx >= grid.height() as i32
+                    || ny >= grid.width() as i32
                     || grid[nx as usize][ny as usize] == b'#'
                 {
                     continue;
@@ -139,6 +141,8 @@ pub fn part2(input: &str) -> u32 {
     valid_cheats
 }
 
+aoc_common::solution!(parse -> Track; u32, u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,21 +168,21 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 0);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 0);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 1511);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 1511);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 0);
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), 0);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 1020507);
+        assert_eq!(part2(&parse(include_str!("input.txt"))), 1020507);
     }
 }
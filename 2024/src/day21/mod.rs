@@ -145,6 +145,8 @@ pub fn part2(input: &str) -> u64 {
     solve(input, 25)
 }
 
+aoc_common::solution!(u64, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,6 +61,8 @@ pub fn part2(input: &str) -> u64 {
         .unwrap()
 }
 
+aoc_common::solution!(u64, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,5 @@
-use arrayvec::ArrayVec;
+use aoc_toolbox::graph::Graph;
 use itertools::Itertools;
-use rustc_hash::FxHashSet;
 
 const N_CHARS: usize = 26;
 const SIZE: usize = N_CHARS * N_CHARS;
@@ -9,47 +8,30 @@ fn starts_with_t(index: usize) -> bool {
     index / N_CHARS == (b't' - b'a') as usize
 }
 
-fn parse(input: &str) -> ([[u8; SIZE]; SIZE], [u8; SIZE]) {
-    let mut adj = [[0u8; SIZE]; SIZE];
-    let mut vertices = [0u8; SIZE];
+/// Index of a computer from its two letters name
+fn vertex(name: &str) -> usize {
+    let (a, b) = name.as_bytes().iter().collect_tuple().unwrap();
+    (a - b'a') as usize * N_CHARS + (b - b'a') as usize
+}
 
+/// Network of the computers, connected both ways
+fn parse(input: &str) -> Graph {
+    let mut graph = Graph::new(SIZE);
     input.trim_ascii().lines().for_each(|line| {
-        let (a, b) = line
-            .split("-")
-            .map(|s| {
-                let (a, b) = s.as_bytes().iter().collect_tuple().unwrap();
-                (a - b'a') as usize * N_CHARS + (b - b'a') as usize
-            })
-            .collect_tuple()
-            .unwrap();
-
-        vertices[a] = 1;
-        vertices[b] = 1;
-
-        adj[a][b] = 1;
-        adj[b][a] = 1;
+        let (a, b) = line.split_once('-').unwrap();
+        graph.add_undirected_edge(vertex(a), vertex(b));
     });
 
-    (adj, vertices)
+    graph
 }
 
-pub fn part1(input: &str) -> u32 {
-    let (adj, vertices) = parse(input);
+pub fn part1(graph: &Graph) -> u32 {
     let mut total = 0;
 
-    for (x, present) in vertices.iter().enumerate() {
-        if *present == 0 {
-            continue;
-        }
-        for (y, &connected) in adj[x].iter().enumerate() {
-            if connected == 0 {
-                continue;
-            }
-            for (z, &connected) in adj[y].iter().enumerate() {
-                if connected == 0 {
-                    continue;
-                }
-                if adj[z][x] == 1 && [x, y, z].iter().any(|&v| starts_with_t(v)) {
+    for x in 0..graph.len() {
+        for &y in graph.successors(x) {
+            for &z in graph.successors(y) {
+                if graph.contains_edge(z, x) && [x, y, z].iter().any(|&v| starts_with_t(v)) {
                     total += 1;
                 }
             }
@@ -60,32 +42,9 @@ pub fn part1(input: &str) -> u32 {
     total / 6
 }
 
-pub fn part2(input: &str) -> String {
-    let (adj, vertices) = parse(input);
-    let mut cliques: FxHashSet<ArrayVec<usize, SIZE>> = FxHashSet::default();
-
-    for (vertex_index, present) in vertices.iter().enumerate() {
-        if *present == 0 {
-            continue;
-        }
-        let mut clique = ArrayVec::<usize, SIZE>::new();
-        clique.push(vertex_index);
-        for (vertex, present) in vertices.iter().enumerate() {
-            if *present == 0 || vertex == vertex_index {
-                continue;
-            }
-            let is_in_clique = clique.iter().all(|&v| adj[vertex][v] == 1);
-            if is_in_clique {
-                clique.push(vertex);
-            }
-        }
-        clique.sort();
-        cliques.insert(clique);
-    }
-
-    let max_clique = cliques.iter().max_by_key(|c| c.len()).unwrap();
-
-    max_clique
+pub fn part2(graph: &Graph) -> String {
+    graph
+        .maximum_clique()
         .iter()
         .map(|&v| {
             format!(
@@ -97,6 +56,8 @@ pub fn part2(input: &str) -> String {
         .join(",")
 }
 
+aoc_common::solution!(parse -> Graph; u32, String);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,23 +100,23 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 7);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 7);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 1062);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 1062);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), "co,de,ka,ta");
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), "co,de,ka,ta");
     }
 
     #[test]
     fn test_part2() {
         assert_eq!(
-            part2(include_str!("input.txt")),
+            part2(&parse(include_str!("input.txt"))),
             "bz,cs,fx,ms,oz,po,sy,uh,uv,vw,xu,zj,zm"
         );
     }
@@ -89,6 +89,8 @@ pub fn part2(_input: &str) -> String {
     "".to_string()
 }
 
+aoc_common::solution!(u64, String);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,6 +40,9 @@ pub fn part1(input: &str) -> u32 {
     })
 }
 
+// the second star is given for free once every other star is collected
+aoc_common::solution!(u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,6 +51,8 @@ pub fn part2(input: &str) -> u32 {
     sum
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -1,21 +1,21 @@
+use aoc_toolbox::ArrayGrid;
+
 const SIZE: usize = 140;
 
+type Grid = ArrayGrid<u8, SIZE>;
+
 pub fn part1(input: &str) -> u16 {
-    let mut grid = [[b'.'; SIZE]; SIZE];
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-        });
-    });
+    let grid = Grid::parse(input, b'.', |_, c| c).unwrap();
+    let (width, height) = (grid.width(), grid.height());
 
     let mut sum = 0;
 
-    for i in 0..SIZE {
-        for j in 0..SIZE {
+    for i in 0..height {
+        for j in 0..width {
             // Optimize pattern matching with direct byte comparisons
             if grid[i][j] == b'X' {
                 // Horizontal XMAS
-                if j + 3 < SIZE
+                if j + 3 < width
                     && grid[i][j + 1] == b'M'
                     && grid[i][j + 2] == b'A'
                     && grid[i][j + 3] == b'S'
@@ -24,7 +24,7 @@ pub fn part1(input: &str) -> u16 {
                 }
 
                 // Vertical XMAS
-                if i + 3 < SIZE
+                if i + 3 < height
                     && grid[i + 1][j] == b'M'
                     && grid[i + 2][j] == b'A'
                     && grid[i + 3][j] == b'S'
@@ -33,8 +33,8 @@ pub fn part1(input: &str) -> u16 {
                 }
 
                 // Diagonal right XMAS
-                if i + 3 < SIZE
-                    && j + 3 < SIZE
+                if i + 3 < height
+                    && j + 3 < width
                     && grid[i + 1][j + 1] == b'M'
                     && grid[i + 2][j + 2] == b'A'
                     && grid[i + 3][j + 3] == b'S'
@@ -43,7 +43,7 @@ pub fn part1(input: &str) -> u16 {
                 }
 
                 // Diagonal left XMAS
-                if i + 3 < SIZE
+                if i + 3 < height
                     && j >= 3
                     && grid[i + 1][j - 1] == b'M'
                     && grid[i + 2][j - 2] == b'A'
@@ -55,7 +55,7 @@ pub fn part1(input: &str) -> u16 {
 
             if grid[i][j] == b'S' {
                 // Similar optimizations for SAMX patterns
-                if j + 3 < SIZE
+                if j + 3 < width
                     && grid[i][j + 1] == b'A'
                     && grid[i][j + 2] == b'M'
                     && grid[i][j + 3] == b'X'
@@ -63,7 +63,7 @@ pub fn part1(input: &str) -> u16 {
                     sum += 1;
                 }
 
-                if i + 3 < SIZE
+                if i + 3 < height
                     && grid[i + 1][j] == b'A'
                     && grid[i + 2][j] == b'M'
                     && grid[i + 3][j] == b'X'
@@ -71,8 +71,8 @@ pub fn part1(input: &str) -> u16 {
                     sum += 1;
                 }
 
-                if i + 3 < SIZE
-                    && j + 3 < SIZE
+                if i + 3 < height
+                    && j + 3 < width
                     && grid[i + 1][j + 1] == b'A'
                     && grid[i + 2][j + 2] == b'M'
                     && grid[i + 3][j + 3] == b'X'
@@ -80,7 +80,7 @@ pub fn part1(input: &str) -> u16 {
                     sum += 1;
                 }
 
-                if i + 3 < SIZE
+                if i + 3 < height
                     && j >= 3
                     && grid[i + 1][j - 1] == b'A'
                     && grid[i + 2][j - 2] == b'M'
@@ -96,7 +96,7 @@ pub fn part1(input: &str) -> u16 {
 }
 
 #[inline(always)]
-fn is_x_mas(grid: &[[u8; SIZE]; SIZE], i: usize, j: usize) -> bool {
+fn is_x_mas(grid: &Grid, i: usize, j: usize) -> bool {
     if grid[i + 1][j + 1] != b'A' {
         return false;
     }
@@ -134,16 +134,11 @@ fn is_x_mas(grid: &[[u8; SIZE]; SIZE], i: usize, j: usize) -> bool {
 }
 
 pub fn part2(input: &str) -> u16 {
-    let mut grid = [[b'.'; SIZE]; SIZE];
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-        });
-    });
+    let grid = Grid::parse(input, b'.', |_, c| c).unwrap();
     let mut sum = 0;
 
-    for i in 0..SIZE - 2 {
-        for j in 0..SIZE - 2 {
+    for i in 0..grid.height().saturating_sub(2) {
+        for j in 0..grid.width().saturating_sub(2) {
             if is_x_mas(&grid, i, j) {
                 sum += 1;
             }
@@ -152,6 +147,8 @@ pub fn part2(input: &str) -> u16 {
     sum
 }
 
+aoc_common::solution!(u16, u16);
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
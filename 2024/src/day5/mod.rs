@@ -1,9 +1,12 @@
+use aoc_toolbox::graph::Graph;
+
 const UPDATE_MAX_SIZE: usize = 23; // real size is 23, we store 2 extra values. 1 for the middle value and 1 for the size of the update
 const UPDATES_LEN: usize = 220;
 const MAX_SIZE: usize = 100;
 type AdjMatrix = [[bool; MAX_SIZE]; MAX_SIZE];
+type Updates = [[u8; UPDATE_MAX_SIZE + 2]; UPDATES_LEN];
 
-fn parse(input: &str) -> (AdjMatrix, [[u8; UPDATE_MAX_SIZE + 2]; UPDATES_LEN]) {
+fn parse(input: &str) -> (AdjMatrix, Updates) {
     let mut it = input.split("\n\n");
     let mut matrix = [[false; MAX_SIZE]; MAX_SIZE];
 
@@ -46,11 +49,9 @@ fn is_update_correct_order(update: &[u8], matrix: &AdjMatrix) -> bool {
     true
 }
 
-pub fn part1(input: &str) -> u16 {
-    let (matrix, updates) = parse(input);
-
-    updates.into_iter().fold(0, |acc, update| {
-        acc + if is_update_correct_order(&update, &matrix) {
+pub fn part1((matrix, updates): &(AdjMatrix, Updates)) -> u16 {
+    updates.iter().fold(0, |acc, update| {
+        acc + if is_update_correct_order(update, matrix) {
             update[UPDATE_MAX_SIZE] as u16
         } else {
             0
@@ -58,31 +59,36 @@ pub fn part1(input: &str) -> u16 {
     })
 }
 
+/// Store the middle page of `update` once sorted by the rules
 fn sort_update(update: &mut [u8], matrix: &AdjMatrix) {
     let len = update[UPDATE_MAX_SIZE + 1] as usize;
-    for _i in 0..len {
-        for j in 0..len - 1 {
-            let a = update[j] as usize;
-            let b = update[j + 1] as usize;
-            if matrix[b][a] {
-                update.swap(j, j + 1);
+    // the rules between the pages of the update, the page `update[i]` being the node `i`
+    let mut rules = Graph::new(len);
+    for i in 0..len {
+        for j in 0..len {
+            if matrix[update[i] as usize][update[j] as usize] {
+                rules.add_edge(i, j);
             }
         }
     }
-    update[UPDATE_MAX_SIZE] = update[len / 2];
+    let order = rules
+        .topological_sort()
+        .expect("the rules of an update have no cycle");
+    update[UPDATE_MAX_SIZE] = update[order[len / 2]];
 }
 
-pub fn part2(input: &str) -> u16 {
-    let (matrix, updates) = parse(input);
-
-    updates.into_iter().fold(0, |acc, mut update| {
-        if is_update_correct_order(&update, &matrix) {
+pub fn part2((matrix, updates): &(AdjMatrix, Updates)) -> u16 {
+    updates.iter().copied().fold(0, |acc, mut update| {
+        if is_update_correct_order(&update, matrix) {
             return acc;
         }
-        sort_update(&mut update, &matrix);
+        sort_update(&mut update, matrix);
         acc + update[UPDATE_MAX_SIZE] as u16
     })
 }
+
+aoc_common::solution!(parse -> (AdjMatrix, Updates); u16, u16);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,21 +127,21 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 143);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 143);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 4578);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 4578);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 123);
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), 123);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 6179);
+        assert_eq!(part2(&parse(include_str!("input.txt"))), 6179);
     }
 }
@@ -1,81 +1,43 @@
-const GRID_SIZE: usize = 130;
+use aoc_toolbox::{ArrayGrid, Direction4, Point};
 
-#[derive(Clone)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+/// Largest width and height of the inputs
+const GRID_SIZE: usize = 130;
 
-impl Direction {
-    #[inline(always)]
-    fn turn(&self) -> Self {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
+type Grid = ArrayGrid<u8, GRID_SIZE>;
 
-    #[inline(always)]
-    fn advance(&self, pos: (usize, usize)) -> Option<(usize, usize)> {
-        match self {
-            Direction::Up if pos.0 != 0 => Some((pos.0 - 1, pos.1)),
-            Direction::Right if pos.1 != GRID_SIZE - 1 => Some((pos.0, pos.1 + 1)),
-            Direction::Down if pos.0 != GRID_SIZE - 1 => Some((pos.0 + 1, pos.1)),
-            Direction::Left if pos.1 != 0 => Some((pos.0, pos.1 - 1)),
-            _ => None,
-        }
-    }
-
-    #[inline(always)]
-    fn to_bit(&self) -> u8 {
-        match self {
-            Direction::Up => UP,
-            Direction::Right => RIGHT,
-            Direction::Down => DOWN,
-            Direction::Left => LEFT,
-        }
-    }
+/// Position one step away in `dir`, if still in the grid
+#[inline(always)]
+fn advance(pos: (usize, usize), dir: Direction4, grid: &Grid) -> Option<(usize, usize)> {
+    Point::from(pos)
+        .checked_step(dir, grid.width(), grid.height())
+        .map(Into::into)
 }
 
 const NOT_VISITED: u8 = 0;
-const UP: u8 = 1;
-const RIGHT: u8 = 1 << 2;
-const DOWN: u8 = 1 << 3;
-const LEFT: u8 = 1 << 4;
 
 // TODO: store more information in the grid using bitset operations
 
 pub fn part1(input: &str) -> u16 {
-    let mut grid = [[b'0'; GRID_SIZE]; GRID_SIZE];
     let mut visits = [[false; GRID_SIZE]; GRID_SIZE];
     let mut pos = (0, 0);
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-            if c == b'^' {
-                visits[i][j] = true;
-                pos = (i, j);
-            }
-        });
-    });
+    let grid = Grid::parse(input, b'0', |(i, j), c| {
+        if c == b'^' {
+            visits[i][j] = true;
+            pos = (i, j);
+        }
+        c
+    })
+    .unwrap();
 
     let mut sum = 1;
-    let mut dir = Direction::Up;
+    let mut dir = Direction4::Up;
     let mut previous_pos = pos;
     loop {
         match grid[pos.0][pos.1] {
             b'#' => {
-                dir = dir.turn();
+                dir = dir.turn_right();
                 pos = previous_pos;
             }
-            // we are outside, useful only for example input
-            b'0' => {
-                break;
-            }
             _ => {
                 if !visits[pos.0][pos.1] {
                     sum += 1;
@@ -83,7 +45,7 @@ pub fn part1(input: &str) -> u16 {
                 }
             }
         }
-        if let Some(new_pos) = dir.advance(pos) {
+        if let Some(new_pos) = advance(pos, dir, &grid) {
             previous_pos = pos;
             pos = new_pos;
         } else {
@@ -94,38 +56,36 @@ pub fn part1(input: &str) -> u16 {
 }
 
 pub fn part2(input: &str) -> u16 {
-    let mut grid = [[b'0'; GRID_SIZE]; GRID_SIZE];
     let mut visits = [[NOT_VISITED; GRID_SIZE]; GRID_SIZE];
     let mut pos = (0, 0);
-    input.lines().enumerate().for_each(|(i, line)| {
-        line.bytes().enumerate().for_each(|(j, c)| {
-            grid[i][j] = c;
-            if c == b'^' {
-                visits[i][j] = UP;
-                pos = (i, j);
-            }
-        });
-    });
+    let grid = Grid::parse(input, b'0', |(i, j), c| {
+        if c == b'^' {
+            visits[i][j] = Direction4::Up.bit();
+            pos = (i, j);
+        }
+        c
+    })
+    .unwrap();
 
     let mut sum = 0;
-    let mut path: Vec<((usize, usize), Direction)> = Vec::with_capacity(5_000);
-    path.push((pos, Direction::Up));
+    let mut path: Vec<((usize, usize), Direction4)> = Vec::with_capacity(5_000);
+    path.push((pos, Direction4::Up));
     visit_grid(&mut path, &grid, visits);
-    for i in 2..path.len() {
+    for i in 1..path.len() {
         let (pos, dir) = &path[i];
         // add an obstacle on the way, only if it is not in the previous path
         if visits[pos.0][pos.1] != 0 {
             continue;
         }
 
-        visits[pos.0][pos.1] |= dir.to_bit();
+        visits[pos.0][pos.1] |= dir.bit();
         let mut new_grid = grid;
         new_grid[pos.0][pos.1] = b'#';
 
         // create sub path and turn in front of new obstacle
         let sub_path = &path[0..i];
         let mut sub_path = sub_path.to_vec();
-        sub_path.push((path[i - 1].0, dir.turn()));
+        sub_path.push((path[i - 1].0, dir.turn_right()));
 
         let is_cycle = visit_grid(&mut sub_path, &new_grid, visits);
         if is_cycle {
@@ -136,36 +96,31 @@ pub fn part2(input: &str) -> u16 {
 }
 
 fn visit_grid(
-    path: &mut Vec<((usize, usize), Direction)>,
-    grid: &[[u8; GRID_SIZE]; GRID_SIZE],
+    path: &mut Vec<((usize, usize), Direction4)>,
+    grid: &Grid,
     mut visits: [[u8; GRID_SIZE]; GRID_SIZE],
 ) -> bool {
-    let (mut pos, dir) = path.last().unwrap();
-    let mut dir = dir.clone();
+    let (mut pos, mut dir) = *path.last().unwrap();
     let mut previous_pos = pos;
     loop {
         match grid[pos.0][pos.1] {
             b'#' => {
-                dir = dir.turn();
+                dir = dir.turn_right();
                 pos = previous_pos;
                 path.pop();
             }
-            // we are outside, useful only for example input
-            b'0' => {
-                break;
-            }
             _ => {
-                visits[pos.0][pos.1] |= dir.to_bit();
+                visits[pos.0][pos.1] |= dir.bit();
             }
         }
-        if let Some(new_pos) = dir.advance(pos) {
+        if let Some(new_pos) = advance(pos, dir, grid) {
             previous_pos = pos;
             pos = new_pos;
             // check if guard has already been in this spot in the same direction
-            if (visits[pos.0][pos.1] & dir.to_bit()) != 0 {
+            if (visits[pos.0][pos.1] & dir.bit()) != 0 {
                 return true;
             }
-            path.push((pos, dir.clone()));
+            path.push((pos, dir));
         } else {
             break;
         }
@@ -173,6 +128,93 @@ fn visit_grid(
     false
 }
 
+aoc_common::solution!(u16, u16);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use std::collections::HashSet;
+
+    use aoc_common::Rng;
+    use aoc_toolbox::{Direction4, Point};
+
+    use super::GRID_SIZE;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = (size + 4).min(GRID_SIZE);
+        loop {
+            let mut grid: Vec<Vec<u8>> = (0..side)
+                .map(|_| {
+                    (0..side)
+                        .map(|_| if rng.chance(1, 8) { b'#' } else { b'.' })
+                        .collect()
+                })
+                .collect();
+            let (i, j) = (
+                rng.range(0..=side as u64 - 1),
+                rng.range(0..=side as u64 - 1),
+            );
+            grid[i as usize][j as usize] = b'^';
+
+            // the guard of the inputs always leaves the area
+            let rows: Vec<&[u8]> = grid.iter().map(Vec::as_slice).collect();
+            if walk(&rows).is_some() {
+                return grid
+                    .iter()
+                    .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+                    .collect();
+            }
+        }
+    }
+
+    /// Positions visited by the guard until it leaves the area, `None` when it walks in a loop
+    fn walk(grid: &[&[u8]]) -> Option<HashSet<Point<usize>>> {
+        let (height, width) = (grid.len(), grid[0].len());
+        let mut position = (0..height)
+            .flat_map(|i| (0..width).map(move |j| Point::from((i, j))))
+            .find(|position| grid[position.y][position.x] == b'^')
+            .unwrap();
+        let mut direction = Direction4::Up;
+
+        let mut seen = HashSet::new();
+        while seen.insert((position, direction)) {
+            let Some(next) = position.checked_step(direction, width, height) else {
+                return Some(seen.into_iter().map(|(position, _)| position).collect());
+            };
+            if grid[next.y][next.x] == b'#' {
+                direction = direction.turn_right();
+            } else {
+                position = next;
+            }
+        }
+        None
+    }
+
+    fn reference_part1(input: &str) -> usize {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        walk(&grid).unwrap().len()
+    }
+
+    /// Number of empty positions where an obstruction makes the guard walk in a loop
+    fn reference_part2(input: &str) -> usize {
+        let grid: Vec<Vec<u8>> = input.lines().map(|line| line.as_bytes().to_vec()).collect();
+
+        (0..grid.len())
+            .flat_map(|i| (0..grid[i].len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| {
+                let mut grid = grid.clone();
+                if grid[i][j] != b'.' {
+                    return false;
+                }
+                grid[i][j] = b'#';
+                let rows: Vec<&[u8]> = grid.iter().map(Vec::as_slice).collect();
+                walk(&rows).is_none()
+            })
+            .count()
+    }
+
+    aoc_common::generator!(generate, reference_part1, reference_part2);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +248,24 @@ mod tests {
         assert_eq!(part2(EXAMPLE_INPUT), 6);
     }
 
+    /// The only obstruction making a loop is on the first step of the guard
+    #[test]
+    fn test_part2_obstruction_on_first_step() {
+        let input = indoc! {"
+            ...#.....
+            ...#.....
+            .#.......
+            .........
+            .#.......
+            #...#....
+            ...#..#..
+            .....#^..
+            #.....#..
+        "};
+
+        assert_eq!(part2(input), 1);
+    }
+
     #[test]
     fn test_part2() {
         assert_eq!(part2(include_str!("input.txt")), 1711);
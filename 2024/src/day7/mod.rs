@@ -93,6 +93,78 @@ pub fn part2(input: &str) -> u64 {
     })
 }
 
+aoc_common::solution!(u64, u64);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use aoc_common::Rng;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let values: Vec<u64> = (0..rng.range(2..=6)).map(|_| rng.range(1..=999)).collect();
+                let total = if rng.chance(1, 2) {
+                    values[1..]
+                        .iter()
+                        .try_fold(values[0], |acc, &value| match rng.range(0..=2) {
+                            0 => acc.checked_add(value),
+                            1 => acc.checked_mul(value),
+                            _ => format!("{}{}", acc, value).parse().ok(),
+                        })
+                } else {
+                    None
+                };
+                let total = total.unwrap_or_else(|| rng.range(1..=1_000_000));
+
+                let values: Vec<String> = values.iter().map(u64::to_string).collect();
+                format!("{}: {}\n", total, values.join(" "))
+            })
+            .collect()
+    }
+
+    /// Sum of the totals reachable by trying every combination of the first `operators` operators
+    fn reference(input: &str, operators: u64) -> u64 {
+        input
+            .lines()
+            .map(|line| {
+                let (total, values) = line.split_once(": ").unwrap();
+                let total: u64 = total.parse().unwrap();
+                let values: Vec<u64> = values.split(' ').map(|v| v.parse().unwrap()).collect();
+
+                let combinations = operators.pow(values.len() as u32 - 1);
+                let solvable = (0..combinations).any(|mut combination| {
+                    let result = values[1..].iter().try_fold(values[0], |acc, &value| {
+                        let operator = combination % operators;
+                        combination /= operators;
+                        match operator {
+                            0 => acc.checked_add(value),
+                            1 => acc.checked_mul(value),
+                            _ => format!("{}{}", acc, value).parse().ok(),
+                        }
+                    });
+                    result == Some(total)
+                });
+
+                if solvable {
+                    total
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn reference_part1(input: &str) -> u64 {
+        reference(input, 2)
+    }
+
+    fn reference_part2(input: &str) -> u64 {
+        reference(input, 3)
+    }
+
+    aoc_common::generator!(generate, reference_part1, reference_part2);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
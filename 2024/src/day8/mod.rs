@@ -3,7 +3,7 @@ const ASCII_RANGE: usize = 128;
 const POS_SIZE: usize = 4; // each antenna has at most 4 occurrences
 
 #[derive(Copy, Clone)]
-struct CharInfo {
+pub struct CharInfo {
     positions: [(usize, usize); POS_SIZE],
     count: u8,
 }
@@ -46,8 +46,7 @@ fn parse(input: &str) -> (CharData, usize) {
     (char_data, size)
 }
 
-pub fn part1(input: &str) -> u32 {
-    let (char_data, size) = parse(input);
+pub fn part1(&(ref char_data, size): &(CharData, usize)) -> u32 {
     let mut a_nodes = [[false; GRID_SIZE]; GRID_SIZE];
     let mut total = 0;
 
@@ -87,8 +86,7 @@ pub fn part1(input: &str) -> u32 {
     total
 }
 
-pub fn part2(input: &str) -> u32 {
-    let (char_data, size) = parse(input);
+pub fn part2(&(ref char_data, size): &(CharData, usize)) -> u32 {
     let mut a_nodes = [[false; GRID_SIZE]; GRID_SIZE];
     let mut total = 0;
 
@@ -126,6 +124,8 @@ pub fn part2(input: &str) -> u32 {
     total
 }
 
+aoc_common::solution!(parse -> (CharData, usize); u32, u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,21 +148,21 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 14);
+        assert_eq!(part1(&parse(EXAMPLE_INPUT)), 14);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 359);
+        assert_eq!(part1(&parse(include_str!("input.txt"))), 359);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 34);
+        assert_eq!(part2(&parse(EXAMPLE_INPUT)), 34);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 1293);
+        assert_eq!(part2(&parse(include_str!("input.txt"))), 1293);
     }
 }
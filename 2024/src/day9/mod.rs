@@ -1,19 +1,22 @@
-#[derive(Debug)]
-struct File {
+#[derive(Debug, Clone)]
+pub struct File {
     position: u64,
     id: u64,
     blocks: u64,
 }
 
-#[derive(Debug)]
-struct EmptySpace {
+#[derive(Debug, Clone)]
+pub struct EmptySpace {
     position: u64,
     blocks: u64,
 }
 
 const CAPACITY: usize = 10_000;
 
-fn parse_input(input: &str) -> (Vec<File>, Vec<EmptySpace>) {
+/// Files and empty spaces of the disk, in the order of their positions
+type Disk = (Vec<File>, Vec<EmptySpace>);
+
+fn parse_input(input: &str) -> Disk {
     let mut files: Vec<File> = Vec::with_capacity(CAPACITY);
     let mut empty_spaces: Vec<EmptySpace> = Vec::with_capacity(CAPACITY);
 
@@ -58,14 +61,14 @@ fn checksum(files: &[File]) -> u64 {
     )
 }
 
-pub fn part1(input: &str) -> u64 {
-    let (mut files, empty_spaces) = parse_input(input);
+pub fn part1((files, empty_spaces): &Disk) -> u64 {
+    let mut files = files.clone();
 
     'outer: for empty_space in empty_spaces {
         let EmptySpace {
             mut position,
             mut blocks,
-        } = empty_space;
+        } = *empty_space;
         while blocks > 0 {
             let last_file = files.pop().unwrap();
 
@@ -110,8 +113,8 @@ pub fn part1(input: &str) -> u64 {
     checksum(&files)
 }
 
-pub fn part2(input: &str) -> u64 {
-    let (mut files, mut empty_spaces) = parse_input(input);
+pub fn part2((files, empty_spaces): &Disk) -> u64 {
+    let (mut files, mut empty_spaces) = (files.clone(), empty_spaces.clone());
 
     let files_len = files.len();
     let mut new_files: Vec<File> = Vec::with_capacity(files_len);
@@ -136,6 +139,8 @@ pub fn part2(input: &str) -> u64 {
     checksum(&new_files)
 }
 
+aoc_common::solution!(parse_input -> Disk; u64, u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,21 +152,27 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 1928);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 1928);
     }
 
     #[test]
     fn test_part1() {
-        assert_eq!(part1(include_str!("input.txt")), 6385338159127);
+        assert_eq!(
+            part1(&parse_input(include_str!("input.txt"))),
+            6385338159127
+        );
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 2858);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 2858);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(include_str!("input.txt")), 6415163624282);
+        assert_eq!(
+            part2(&parse_input(include_str!("input.txt"))),
+            6415163624282
+        );
     }
 }
@@ -27,3 +27,16 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
+
+/// All the days solved for 2024, sorted by day
+pub fn days() -> Vec<&'static aoc_common::Registration> {
+    aoc_common::days(2024)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_every_day_is_registered() {
+        aoc_common::assert_days_registered(2024, env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1 @@
+aoc_bench::main!(rust_2025::days());
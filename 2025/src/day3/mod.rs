@@ -56,6 +56,8 @@ pub fn part2(input: &str) -> usize {
     })
 }
 
+aoc_common::solution!(u32, usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
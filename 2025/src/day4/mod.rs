@@ -1,91 +1,35 @@
-pub fn part1(input: &str) -> u32 {
-    let grid: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
-
-    let mut rolls = 0;
-
-    for i in 0..grid.len() {
-        for j in 0..grid[i].len() {
-            // skip if cell is a dot
-            if grid[i][j] == b'.' {
-                continue;
-            }
-
-            // all eight adjacent cells, discarding out-of-bounds
-            let mut forklifts = 0;
-            for di in -1..=1 {
-                for dj in -1..=1 {
-                    if di == 0 && dj == 0 {
-                        continue;
-                    }
-                    let ni = i as isize + di;
-                    let nj = j as isize + dj;
-                    if ni >= 0
-                        && ni < grid.len() as isize
-                        && nj >= 0
-                        && nj < grid[i].len() as isize
-                        && grid[ni as usize][nj as usize] == b'@'
-                        && forklifts < 4
-                    {
-                        forklifts += 1;
-                    }
-                }
-            }
+use aoc_toolbox::{Grid, Point};
+
+/// Whether the roll at `position` has fewer than four rolls among its eight adjacent cells
+fn is_accessible(grid: &Grid<u8>, position: Point<usize>) -> bool {
+    grid.neighbours8(position)
+        .filter(|&neighbour| grid[neighbour] == b'@')
+        .take(4)
+        .count()
+        < 4
+}
 
-            if forklifts < 4 {
-                rolls += 1;
-            }
-        }
-    }
+pub fn part1(input: &str) -> u32 {
+    let grid = Grid::parse(input, |_, b| b).unwrap();
 
-    rolls
+    grid.find_all(|&cell| cell == b'@')
+        .filter(|&position| is_accessible(&grid, position))
+        .count() as u32
 }
 
 pub fn part2(input: &str) -> u32 {
-    let mut grid: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+    let mut grid = Grid::parse(input, |_, b| b).unwrap();
     let mut removed = 0;
 
     loop {
         let mut changed = false;
-        for i in 0..grid.len() {
-            for j in 0..grid[i].len() {
-                // skip if cell is a dot
-                if grid[i][j] == b'.' {
-                    continue;
-                }
-
-                // all eight adjacent cells, discarding out-of-bounds
-                let mut forklifts = 0;
-                for di in -1..=1 {
-                    for dj in -1..=1 {
-                        if di == 0 && dj == 0 {
-                            continue;
-                        }
-                        let ni = i as isize + di;
-                        let nj = j as isize + dj;
-                        if ni >= 0
-                            && ni < grid.len() as isize
-                            && nj >= 0
-                            && nj < grid[i].len() as isize
-                            && grid[ni as usize][nj as usize] == b'@'
-                            && forklifts < 4
-                        {
-                            forklifts += 1;
-                        }
-                    }
-                }
-
-                if forklifts < 4 {
-                    grid[i][j] = b'.';
-                    changed = true;
-                    removed += 1;
-                }
+        for position in grid.positions() {
+            if grid[position] == b'@' && is_accessible(&grid, position) {
+                grid[position] = b'.';
+                changed = true;
+                removed += 1;
             }
         }
-        // // print the grid
-        // for row in &grid {
-        //     println!("{}", String::from_utf8_lossy(row));
-        // }
-        // println!();
         if !changed {
             break;
         }
@@ -94,6 +38,8 @@ pub fn part2(input: &str) -> u32 {
     removed
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
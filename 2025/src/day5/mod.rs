@@ -52,6 +52,64 @@ pub fn part2(input: &str) -> usize {
         .fold(0, |acc, &(start, end)| acc + (end - start + 1))
 }
 
+aoc_common::solution!(u32, usize);
+
+#[cfg(any(test, feature = "check"))]
+mod check {
+    use std::collections::HashSet;
+
+    use aoc_common::Rng;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let max = size as u64 * 10;
+        let ranges: Vec<String> = (0..size)
+            .map(|_| {
+                let start = rng.range(1..=max);
+                format!("{}-{}\n", start, start + rng.range(0..=10))
+            })
+            .collect();
+        let ids: Vec<String> = (0..size)
+            .map(|_| format!("{}\n", rng.range(1..=max + 10)))
+            .collect();
+
+        format!("{}\n{}", ranges.concat(), ids.concat())
+    }
+
+    fn reference_ranges(input: &str) -> Vec<(usize, usize)> {
+        input
+            .split("\n\n")
+            .next()
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let (start, end) = line.split_once('-').unwrap();
+                (start.parse().unwrap(), end.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn reference_part1(input: &str) -> usize {
+        let ranges = reference_ranges(input);
+        let ids = input.split("\n\n").nth(1).unwrap().lines();
+
+        ids.map(|id| id.parse::<usize>().unwrap())
+            .filter(|id| ranges.iter().any(|range| (range.0..=range.1).contains(id)))
+            .count()
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        let ranges = reference_ranges(input);
+
+        ranges
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    aoc_common::generator!(generate, reference_part1, reference_part2);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +83,8 @@ pub fn part2(input: &str) -> usize {
     })
 }
 
+aoc_common::solution!(usize, usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,6 +68,8 @@ pub fn part2(input: &str) -> usize {
     unreachable!();
 }
 
+aoc_common::solution!(usize, usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,3 +5,16 @@ pub mod day4;
 pub mod day5;
 pub mod day6;
 pub mod day7;
+
+/// All the days solved for 2025, sorted by day
+pub fn days() -> Vec<&'static aoc_common::Registration> {
+    aoc_common::days(2025)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_every_day_is_registered() {
+        aoc_common::assert_days_registered(2025, env!("CARGO_MANIFEST_DIR"));
+    }
+}
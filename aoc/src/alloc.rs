@@ -0,0 +1,153 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Heap usage of a step, on every thread
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    /// Number of allocations, reallocations included
+    pub count: u64,
+    /// Bytes allocated, counting only the growth of reallocations
+    pub bytes: u64,
+    /// Largest heap growth above the heap size at the start of the step
+    pub peak: u64,
+}
+
+/// The global allocator only with the `allocations` feature, so that the other builds do not check
+/// whether to count on every allocation
+#[cfg_attr(feature = "allocations", global_allocator)]
+pub static ALLOCATOR: Counting = Counting::new();
+
+/// The system allocator, counting the allocations once enabled
+pub struct Counting {
+    enabled: AtomicBool,
+    count: AtomicU64,
+    bytes: AtomicU64,
+    /// Heap size relative to the start of the measure, negative once older memory is freed
+    current: AtomicI64,
+    peak: AtomicI64,
+}
+
+impl Counting {
+    pub const fn new() -> Self {
+        Counting {
+            enabled: AtomicBool::new(false),
+            count: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            current: AtomicI64::new(0),
+            peak: AtomicI64::new(0),
+        }
+    }
+
+    pub fn enable(&self) {
+        self.enabled.store(true, Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Relaxed)
+    }
+
+    /// Run `f` and count its allocations.
+    ///
+    /// The counters are shared by every thread, so nothing else should allocate meanwhile.
+    pub fn measure(&self, f: impl FnOnce()) -> Allocations {
+        self.count.store(0, Relaxed);
+        self.bytes.store(0, Relaxed);
+        self.current.store(0, Relaxed);
+        self.peak.store(0, Relaxed);
+
+        f();
+
+        Allocations {
+            count: self.count.load(Relaxed),
+            bytes: self.bytes.load(Relaxed),
+            peak: self.peak.load(Relaxed).max(0) as u64,
+        }
+    }
+
+    fn grow(&self, bytes: usize) {
+        self.bytes.fetch_add(bytes as u64, Relaxed);
+        let current = self.current.fetch_add(bytes as i64, Relaxed) + bytes as i64;
+        self.peak.fetch_max(current, Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.current.fetch_sub(bytes as i64, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && self.is_enabled() {
+            self.count.fetch_add(1, Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && self.is_enabled() {
+            self.count.fetch_add(1, Relaxed);
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if self.is_enabled() {
+            self.shrink(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && self.is_enabled() {
+            self.count.fetch_add(1, Relaxed);
+            if new_size > layout.size() {
+                self.grow(new_size - layout.size());
+            } else {
+                self.shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        // a counter of its own, as the global one also counts the other tests
+        let counting = Counting::new();
+        let layout = Layout::from_size_align(32, 8).unwrap();
+        let allocations = || unsafe {
+            let a = counting.alloc(layout);
+            let a = counting.realloc(a, layout, 64);
+            let b = counting.alloc_zeroed(layout);
+            counting.dealloc(a, Layout::from_size_align(64, 8).unwrap());
+            let b = counting.realloc(b, layout, 16);
+            counting.dealloc(b, Layout::from_size_align(16, 8).unwrap());
+        };
+
+        assert_eq!(counting.measure(allocations), Allocations::default());
+
+        counting.enable();
+        assert_eq!(
+            counting.measure(allocations),
+            Allocations {
+                count: 4,
+                bytes: 32 + 32 + 32,
+                peak: 96,
+            }
+        );
+        assert_eq!(counting.measure(|| {}), Allocations::default());
+    }
+}
@@ -0,0 +1,172 @@
+use std::{collections::HashMap, path::Path};
+
+use serde::Deserialize;
+
+use crate::run::DayRun;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    fn into_string(self) -> String {
+        match self {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Answers to a single input stored at `path` as
+///
+/// ```toml
+/// part1 = 11
+/// part2 = "some,text"
+/// ```
+pub fn load_input_answers(path: &Path) -> Result<[Option<String>; 2], String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    let answers: DayAnswers = toml::from_str(&content)
+        .map_err(|error| format!("invalid answers in {}: {}", path.display(), error))?;
+
+    Ok([answers.part1, answers.part2].map(|answer| answer.map(Answer::into_string)))
+}
+
+/// Result of checking an answer against the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Ok,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Ok => "ok",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Known answers to the puzzle inputs, stored in an `answers.toml` for each year as
+///
+/// ```toml
+/// [1]
+/// part1 = 984
+/// part2 = "some,text"
+/// ```
+#[derive(Debug, Default)]
+pub struct AnswerStore(HashMap<(u16, u8, u8), String>);
+
+impl AnswerStore {
+    /// Add the answers of `year` stored at `path`, a missing file meaning no known answers
+    pub fn load(&mut self, year: u16, path: &Path) -> Result<(), String> {
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+        let days: HashMap<String, DayAnswers> = toml::from_str(&content)
+            .map_err(|error| format!("invalid answers in {}: {}", path.display(), error))?;
+
+        for (day, answers) in days {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| format!("invalid day `{}` in {}", day, path.display()))?;
+            for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+                if let Some(answer) = answer {
+                    self.insert(year, day, part, answer.into_string());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, answer: String) {
+        self.0.insert((year, day, part), answer);
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Set the verdict of every solved part of `run`
+    pub fn verify(&self, run: &mut DayRun) {
+        for part in run.parts.iter_mut() {
+            let Some(answer) = &part.answer else {
+                continue;
+            };
+            part.verdict = Some(match self.expected(run.year, run.day, part.part) {
+                Some(expected) if expected == answer => Verdict::Ok,
+                Some(expected) => Verdict::Wrong {
+                    expected: expected.to_string(),
+                },
+                None => Verdict::Unknown,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let mut store = AnswerStore::default();
+        store
+            .load(
+                2025,
+                Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../2025/answers.toml")),
+            )
+            .unwrap();
+
+        assert_eq!(store.expected(2025, 1, 1), Some("984"));
+        assert_eq!(store.expected(2025, 1, 2), Some("5657"));
+        assert_eq!(store.expected(2025, 26, 1), None);
+        assert_eq!(store.expected(2024, 1, 1), None);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let mut store = AnswerStore::default();
+
+        assert_eq!(store.load(2025, Path::new("does/not/exist.toml")), Ok(()));
+        assert_eq!(store.expected(2025, 1, 1), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut store = AnswerStore::default();
+        store.0.insert((2025, 1, 1), "1".to_string());
+        store.0.insert((2025, 1, 2), "7".to_string());
+        let day = rust_2025::days()[0];
+        let mut run = crate::run::run_day(day, "R50\nL5\n", &[1, 2], 1);
+
+        store.verify(&mut run);
+        assert_eq!(run.parts[0].verdict, Some(Verdict::Ok));
+        assert_eq!(
+            run.parts[1].verdict,
+            Some(Verdict::Wrong {
+                expected: "7".to_string()
+            })
+        );
+
+        AnswerStore::default().verify(&mut run);
+        assert_eq!(run.parts[0].verdict, Some(Verdict::Unknown));
+    }
+}
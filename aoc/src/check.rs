@@ -0,0 +1,194 @@
+use std::fmt;
+
+use aoc_common::{Generator, Registration, Rng};
+
+use crate::run;
+
+/// Generated input on which the solution of a part disagrees with its reference solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Seed and size generating `input` again
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {}: expected {} but got {} (seed {}, size {}) on the input:\n{}",
+            self.year,
+            self.day,
+            self.part,
+            self.expected,
+            self.actual,
+            self.seed,
+            self.size,
+            self.input
+        )
+    }
+}
+
+/// Parts of `input` whose solution differs from the reference, with both answers
+fn mismatches(day: &Registration, generator: &Generator, input: &str) -> Vec<(u8, String, String)> {
+    let parts: Vec<u8> = (1..=2)
+        .filter(|&part| generator.reference[part as usize - 1].is_some())
+        .collect();
+    let run = run::run_day(day, input, &parts, 1);
+
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let expected = generator.reference[part as usize - 1].unwrap()(input);
+            let actual = match run.part(part)? {
+                run::PartRun {
+                    answer: Some(answer),
+                    ..
+                } => answer.clone(),
+                run::PartRun { status, error, .. } => format!(
+                    "{}: {}",
+                    status.as_str(),
+                    error.as_deref().unwrap_or_default()
+                ),
+            };
+            (actual != expected).then_some((part, expected, actual))
+        })
+        .collect()
+}
+
+/// Size of the input of the `seed`th case, growing from 1 to `max_size`
+fn size(seed: u64, cases: u64, max_size: usize) -> usize {
+    let max_size = max_size.max(1) as u64;
+    (1 + (max_size - 1) * seed / cases.saturating_sub(1).max(1)) as usize
+}
+
+/// Cross-check `day` against the reference solvers of `generator` on `cases` random inputs of
+/// up to `max_size`.
+///
+/// The first mismatch is shrunk to the smallest input of its seed on which the same part fails.
+pub fn check(
+    day: &Registration,
+    generator: &Generator,
+    cases: u64,
+    max_size: usize,
+) -> Result<(), Mismatch> {
+    let generate = |seed, size| (generator.generate)(&mut Rng::new(seed), size);
+
+    for seed in 0..cases {
+        let size = size(seed, cases, max_size);
+        let input = generate(seed, size);
+        let Some((part, expected, actual)) = mismatches(day, generator, &input).into_iter().next()
+        else {
+            continue;
+        };
+
+        let mut mismatch = Mismatch {
+            year: day.year,
+            day: day.day,
+            part,
+            seed,
+            size,
+            input,
+            expected,
+            actual,
+        };
+        for size in 1..size {
+            let input = generate(seed, size);
+            if let Some((_, expected, actual)) = mismatches(day, generator, &input)
+                .into_iter()
+                .find(|&(failed, _, _)| failed == part)
+            {
+                mismatch = Mismatch {
+                    size,
+                    input,
+                    expected,
+                    actual,
+                    ..mismatch
+                };
+                break;
+            }
+        }
+        return Err(mismatch);
+    }
+
+    Ok(())
+}
+
+/// Generator of `day`, if it has one
+pub fn generator(day: &Registration) -> Option<&'static Generator> {
+    aoc_common::generators(day.year)
+        .into_iter()
+        .find(|generator| generator.day == day.day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days() -> Vec<&'static Registration> {
+        [rust_2023::days(), rust_2024::days(), rust_2025::days()].concat()
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(
+            (0..5).map(|seed| size(seed, 5, 9)).collect::<Vec<_>>(),
+            [1, 3, 5, 7, 9]
+        );
+        assert_eq!(size(0, 1, 9), 1);
+        assert_eq!(size(3, 4, 0), 1);
+    }
+
+    /// Every day with a generator agrees with its reference solvers
+    #[test]
+    fn test_generators() {
+        let days: Vec<_> = days()
+            .into_iter()
+            .filter_map(|day| Some((day, generator(day)?)))
+            .collect();
+        assert!(!days.is_empty());
+
+        for (day, generator) in days {
+            if let Err(mismatch) = check(day, generator, 20, 10) {
+                panic!("{}", mismatch);
+            }
+        }
+    }
+
+    fn day5() -> &'static Registration {
+        days()
+            .into_iter()
+            .find(|day| (day.year, day.day) == (2025, 5))
+            .unwrap()
+    }
+
+    /// Reference of part 2 of 2025 day 5, wrong once the input has more than 8 lines
+    fn wrong_on_large_inputs(input: &str) -> String {
+        match input.lines().count() {
+            0..=8 => generator(day5()).unwrap().reference[1].unwrap()(input),
+            _ => "wrong".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_shrink() {
+        let day = day5();
+        let wrong = Generator {
+            reference: [None, Some(wrong_on_large_inputs)],
+            ..*generator(day).unwrap()
+        };
+
+        // the second case is of size 10, shrunk to the first size with more than 8 lines
+        let mismatch = check(day, &wrong, 2, 10).unwrap_err();
+        assert_eq!((mismatch.part, mismatch.seed, mismatch.size), (2, 1, 4));
+        assert_eq!(mismatch.input, (wrong.generate)(&mut Rng::new(1), 4));
+        assert_eq!(mismatch.expected, "wrong");
+        assert!(check(day, generator(day).unwrap(), 2, 10).is_ok());
+    }
+}
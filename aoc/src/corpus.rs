@@ -0,0 +1,251 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Registration;
+
+use crate::{
+    answers::{self, AnswerStore, Verdict},
+    report,
+    run::DayRun,
+};
+
+/// An input of the corpus, stored as `<dir>/<year>/<day>/<name>.txt`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    /// Read from the paired `<name>.toml`, if any
+    pub answers: [Option<String>; 2],
+}
+
+fn numbered_dirs(dir: &Path) -> Result<Vec<(u16, PathBuf)>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|error| format!("cannot read {}: {}", dir.display(), error))?;
+
+    let mut dirs: Vec<_> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let number = entry.file_name().to_str()?.parse().ok()?;
+            entry.path().is_dir().then(|| (number, entry.path()))
+        })
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Every input of the corpus in `dir`, sorted by year, day and name, none if `dir` does not exist
+pub fn load(dir: &Path) -> Result<Vec<Case>, String> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut cases = vec![];
+    for (year, year_dir) in numbered_dirs(dir)? {
+        for (day, day_dir) in numbered_dirs(&year_dir)? {
+            let mut inputs: Vec<PathBuf> = fs::read_dir(&day_dir)
+                .map_err(|error| format!("cannot read {}: {}", day_dir.display(), error))?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+                .collect();
+            inputs.sort();
+
+            for path in inputs {
+                let answers_path = path.with_extension("toml");
+                let answers = if answers_path.exists() {
+                    answers::load_input_answers(&answers_path)?
+                } else {
+                    [None, None]
+                };
+                cases.push(Case {
+                    year,
+                    day: day as u8,
+                    name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+                    path,
+                    answers,
+                });
+            }
+        }
+    }
+
+    Ok(cases)
+}
+
+/// Solves some parts of a day on an input, in this process or in a worker
+pub type Solver<'a> = dyn Fn(&Registration, &str, &[u8]) -> DayRun + 'a;
+
+/// Solve the parts of `day` with a known answer on the input of `case` with `solve`, or both if
+/// none is known
+pub fn run_case(day: &Registration, case: &Case, solve: &Solver) -> DayRun {
+    let mut answers = AnswerStore::default();
+    let mut parts = vec![];
+    for (part, answer) in (1..).zip(&case.answers) {
+        if let Some(answer) = answer {
+            answers.insert(case.year, case.day, part, answer.clone());
+            parts.push(part);
+        }
+    }
+    if parts.is_empty() {
+        parts = vec![1, 2];
+    }
+
+    let input = match fs::read_to_string(&case.path) {
+        Ok(input) => input,
+        Err(error) => {
+            let error = format!("cannot read {}: {}", case.path.display(), error);
+            return DayRun::missing_input(day, &parts, error);
+        }
+    };
+
+    let mut run = solve(day, &input, &parts);
+    answers.verify(&mut run);
+    run
+}
+
+/// Run each case whose day is in `days` with `solve`, returning the table of the results and the
+/// failed cases.
+///
+/// A case without any known answer fails, as its answers are not checked.
+pub fn run(
+    cases: &[Case],
+    days: &[&'static Registration],
+    solve: &Solver,
+) -> (String, Vec<String>) {
+    let mut rows = vec![["Year", "Day", "Input", "Answer 1", "Answer 2"].map(String::from)];
+    let mut failures = vec![];
+
+    for case in cases {
+        let Some(day) = days
+            .iter()
+            .find(|day| (day.year, day.day) == (case.year, case.day))
+        else {
+            continue;
+        };
+
+        let run = run_case(day, case, solve);
+        let unknown = run
+            .parts
+            .iter()
+            .any(|part_run| part_run.verdict == Some(Verdict::Unknown));
+        if !run.is_ok() || unknown {
+            failures.push(case.path.display().to_string());
+        }
+        rows.push([
+            case.year.to_string(),
+            case.day.to_string(),
+            case.name.clone(),
+            report::answer_cell(&run, 1),
+            report::answer_cell(&run, 2),
+        ]);
+    }
+
+    let table = report::align(&rows, [false, false, true, true, true], &[1]);
+    (table, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Read},
+        process::Command,
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::{
+        run,
+        scaffold::workspace_root,
+        worker::{self, Limits},
+    };
+
+    #[test]
+    fn test_load() {
+        let cases = load(&workspace_root().unwrap().join("inputs")).unwrap();
+        let case = cases
+            .iter()
+            .find(|case| (case.year, case.day, case.name.as_str()) == (2025, 1, "example"))
+            .unwrap();
+
+        assert_eq!(case.answers, [Some("3".to_string()), Some("6".to_string())]);
+        assert_eq!(load(Path::new("does/not/exist")), Ok(vec![]));
+    }
+
+    fn registrations() -> Vec<&'static Registration> {
+        [rust_2023::days(), rust_2024::days(), rust_2025::days()].concat()
+    }
+
+    /// Worker of `test_corpus`, serving the parts of the day in `AOC_TEST_WORKER`, as
+    /// `<year> <day> <parts>`, on its standard input
+    #[test]
+    #[ignore = "only run as a worker"]
+    fn serve_case() {
+        let Ok(worker) = std::env::var("AOC_TEST_WORKER") else {
+            return;
+        };
+        let [year, day, parts] = worker.split(' ').collect::<Vec<_>>()[..] else {
+            panic!("invalid worker: {}", worker);
+        };
+        let (year, day) = (year.parse::<u16>().unwrap(), day.parse::<u8>().unwrap());
+        let day = registrations()
+            .into_iter()
+            .find(|registration| (registration.year, registration.day) == (year, day))
+            .unwrap();
+        let parts: Vec<u8> = parts.bytes().map(|part| part - b'0').collect();
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).unwrap();
+
+        run::install_panic_hook();
+        worker::serve(day, &input, &parts, 1);
+    }
+
+    /// Every day solves every input of the corpus, each in a worker so that a hanging day fails
+    #[test]
+    fn test_corpus() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(60)),
+            memory: None,
+        };
+        let solve = |day: &Registration, input: &str, parts: &[u8]| {
+            worker::run_day_with(day, input, parts, limits, |parts| {
+                let parts: String = parts.iter().map(u8::to_string).collect();
+                let mut worker = Command::new(std::env::current_exe().unwrap());
+                worker
+                    .args(["corpus::tests::serve_case", "--exact", "--quiet"])
+                    .args(["--ignored", "--test-threads", "1"])
+                    .env(
+                        "AOC_TEST_WORKER",
+                        format!("{} {} {}", day.year, day.day, parts),
+                    );
+                Ok(worker)
+            })
+        };
+        let cases = load(&workspace_root().unwrap().join("inputs")).unwrap();
+        let (table, failures) = run(&cases, &registrations(), &solve);
+
+        assert!(failures.is_empty(), "\n{}", table);
+    }
+
+    #[test]
+    fn test_unknown_answers_fail() {
+        let dir = std::env::temp_dir().join(format!("aoc-corpus-{}", std::process::id()));
+        let day_dir = dir.join("2025").join("1");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("unchecked.txt"), "R50\nL5\n").unwrap();
+
+        let cases = load(&dir).unwrap();
+        let solve =
+            |day: &Registration, input: &str, parts: &[u8]| run::run_day(day, input, parts, 1);
+        let (table, failures) = run(&cases, &registrations(), &solve);
+
+        assert_eq!(
+            failures,
+            [day_dir.join("unchecked.txt").display().to_string()]
+        );
+        assert!(table.contains("UNKNOWN"), "\n{}", table);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
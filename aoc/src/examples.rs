@@ -0,0 +1,467 @@
+use crate::html;
+
+/// Example and expected answer of a part, as found in the puzzle description
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// How the example of a part changed in the module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Added,
+    Updated,
+    Unchanged,
+    /// The puzzle has no example for the part
+    NotFound,
+}
+
+impl Change {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Change::Added => "new",
+            Change::Updated => "updated",
+            Change::Unchanged => "same",
+            Change::NotFound => "no",
+        }
+    }
+}
+
+/// Every piece of `s` between `start` and the following `end`
+fn between<'a>(s: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    s.split(start)
+        .skip(1)
+        .filter_map(move |rest| rest.split_once(end).map(|(inside, _)| inside))
+}
+
+/// Examples of the parts described in a saved puzzle page, one `<article>` per part.
+///
+/// The example is the first `<pre><code>` block of the part, and the answer its last `<code><em>`.
+pub fn parse_puzzle(html: &str) -> Vec<PartExample> {
+    between(html, "<article", "</article>")
+        .map(|article| PartExample {
+            input: between(article, "<pre><code>", "</code></pre>")
+                .next()
+                .map(|input| {
+                    let input = html::text(input);
+                    if input.ends_with('\n') {
+                        input
+                    } else {
+                        input + "\n"
+                    }
+                }),
+            answer: between(article, "<code><em>", "</em></code>")
+                .last()
+                .map(html::text),
+        })
+        .collect()
+}
+
+fn example_const(name: &str, input: &str) -> String {
+    let mut block = format!("    const {}: &str = indoc! {{\"\n", name);
+    for line in input.lines() {
+        if !line.is_empty() {
+            block.push_str("        ");
+            block.push_str(&line.replace('\\', "\\\\").replace('"', "\\\""));
+        }
+        block.push('\n');
+    }
+    block.push_str("    \"};\n");
+    block
+}
+
+fn answer_literal(answer: &str) -> String {
+    if answer.parse::<i64>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{:?}", answer)
+    }
+}
+
+/// Byte range of the lines from the one containing `start` to the one containing `end` after it
+fn line_range(source: &str, start: usize, end: usize) -> (usize, usize) {
+    let first = source[..start].rfind('\n').map_or(0, |i| i + 1);
+    let last = source[end..]
+        .find('\n')
+        .map_or(source.len(), |i| end + i + 1);
+    (first, last)
+}
+
+/// Index of the semicolon ending the item starting at `start`, skipping string literals
+fn item_end(source: &str, start: usize) -> Option<usize> {
+    let mut i = start;
+    while let Some(c) = source[i..].chars().next() {
+        match c {
+            ';' => return Some(i),
+            '"' => i = string_end(source, i),
+            'r' if source[i + 1..].starts_with(['"', '#']) => i = raw_string_end(source, i)?,
+            _ => {}
+        }
+        i += source[i..].chars().next()?.len_utf8();
+    }
+    None
+}
+
+/// Index of the last character of the raw string literal starting with the `r` at `start`
+fn raw_string_end(source: &str, start: usize) -> Option<usize> {
+    let hashes = source[start + 1..].len() - source[start + 1..].trim_start_matches('#').len();
+    let open = start + 1 + hashes;
+    if !source[open..].starts_with('"') {
+        return Some(start);
+    }
+    let close = format!("\"{}", "#".repeat(hashes));
+    source[open + 1..]
+        .find(&close)
+        .map(|i| open + 1 + i + close.len() - 1)
+}
+
+/// Set the `name` constant to `input`, adding it after the other examples if it does not exist
+fn set_example(source: &mut String, name: &str, input: &str) -> Result<Change, String> {
+    let block = example_const(name, input);
+    let end_of = |source: &str, start| {
+        item_end(source, start).ok_or_else(|| format!("cannot find the end of `{}`", name))
+    };
+
+    if let Some(start) = source.find(&format!("const {}: &str", name)) {
+        let (first, last) = line_range(source, start, end_of(source, start)?);
+        if source[first..last] == block {
+            return Ok(Change::Unchanged);
+        }
+        source.replace_range(first..last, &block);
+        return Ok(Change::Updated);
+    }
+
+    match source.rfind("const EXAMPLE_INPUT") {
+        Some(start) => {
+            let position = line_range(source, start, end_of(source, start)?).1;
+            source.insert_str(position, &block);
+        }
+        None => {
+            let test = source.find("    #[test]").unwrap_or(source.len());
+            source.insert_str(test, &format!("{}\n", block));
+        }
+    }
+    Ok(Change::Added)
+}
+
+/// Index of the quote closing the string literal opened at `open`
+fn string_end(source: &str, open: usize) -> usize {
+    let mut escaped = false;
+    for (i, c) in source[open + 1..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return open + 1 + i,
+            _ => {}
+        }
+    }
+    source.len()
+}
+
+/// Index of the parenthesis closing the one at `open`, ignoring the ones in string literals
+fn closing_paren(source: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Arguments of the macro call whose parentheses are at `open` and `close`, as byte ranges
+fn arguments(source: &str, open: usize, close: usize) -> Vec<(usize, usize)> {
+    let mut arguments = vec![];
+    let mut start = open + 1;
+    let mut i = start;
+    while i < close {
+        match source[i..].chars().next().unwrap() {
+            '"' => i = string_end(source, i),
+            '(' | '[' | '{' => i = closing_paren(source, i).unwrap(),
+            ',' => {
+                arguments.push((start, i));
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if !source[start..close].trim().is_empty() {
+        arguments.push((start, close));
+    }
+    arguments
+}
+
+/// Set the expected value of the assertion checking `part` in the example test, creating the test if needed
+fn set_expected(source: &mut String, part: u8, example: &str, answer: &str) {
+    let literal = answer_literal(answer);
+    let name = format!("part{}", part);
+
+    let test = [
+        format!("fn test_example_{}()", name),
+        "fn test_example()".to_string(),
+    ]
+    .into_iter()
+    .find_map(|test| source.find(&test));
+    let Some(test) = test else {
+        let position = source.trim_end().rfind('}').unwrap();
+        let position = source[..position].rfind('\n').map_or(0, |i| i + 1);
+        let test = format!(
+            "\n    #[test]\n    fn test_example_{name}() {{\n        assert_eq!({name}({example}), {literal});\n    }}\n",
+        );
+        return source.insert_str(position, &test);
+    };
+
+    let body_start = test + source[test..].find('{').unwrap();
+    let body_end = closing_paren(source, body_start).unwrap();
+    let asserts: Vec<(usize, usize)> = source[body_start..body_end]
+        .match_indices("assert_eq!(")
+        .map(|(i, call)| body_start + i + call.len() - 1)
+        .map(|open| (open, closing_paren(source, open).unwrap()))
+        .collect();
+
+    // the assertion on this part, or the last one of a test dedicated to it
+    let dedicated = source[test..body_start].contains(&name);
+    let assert = asserts
+        .iter()
+        .find(|&&(open, close)| {
+            arguments(source, open, close)
+                .first()
+                .is_some_and(|&(start, end)| source[start..end].trim().starts_with(&name))
+        })
+        .or(asserts.last().filter(|_| dedicated));
+    let Some(&(open, close)) = assert else {
+        return;
+    };
+
+    if let Some(&(start, end)) = arguments(source, open, close).last() {
+        let trimmed = source[start..end].trim();
+        let start = start + source[start..end].find(trimmed).unwrap();
+        source.replace_range(start..start + trimmed.len(), &literal);
+    }
+
+    // a part whose example was just added may still be tested on the example of the other part
+    if dedicated && example != "EXAMPLE_INPUT" {
+        let body_end = closing_paren(source, body_start).unwrap();
+        let body = &source[body_start..body_end];
+        if !body.contains(example) {
+            let body = body.replace("EXAMPLE_INPUT)", &format!("{})", example));
+            source.replace_range(body_start..body_end, &body);
+        }
+    }
+}
+
+/// Update the examples and example tests of a day module `source` with `parts`, along with how
+/// the example of each part changed
+pub fn update_module(source: &str, parts: &[PartExample]) -> Result<(String, Vec<Change>), String> {
+    let mut source = source.to_string();
+    let mut changes = vec![];
+    let first_input = parts.first().and_then(|part| part.input.clone());
+
+    for (part, example) in (1..=2).zip(parts) {
+        let Some(input) = example.input.clone().or(first_input.clone()) else {
+            changes.push(Change::NotFound);
+            continue;
+        };
+
+        let dedicated = format!("EXAMPLE_INPUT_PART_{}", part);
+        let name = if source.contains(&format!("const {}: &str", dedicated))
+            || (part == 2 && Some(&input) != first_input.as_ref())
+        {
+            dedicated
+        } else {
+            "EXAMPLE_INPUT".to_string()
+        };
+        changes.push(set_example(&mut source, &name, &input)?);
+
+        if let Some(answer) = &example.answer {
+            set_expected(&mut source, part, &name, answer);
+        }
+    }
+
+    Ok((source, changes))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const PUZZLE: &str = indoc! {r#"
+        <main>
+        <article class="day-desc"><h2>--- Day 1: Test ---</h2>
+        <p>For example:</p>
+        <pre><code>3   4
+        4   3
+        </code></pre>
+        <p>Not the answer: <code><em>1</em></code>, but <code>x &lt; y</code>: <code><em>11</em></code>.</p>
+        </article>
+        <p>Your puzzle answer was <code>1234</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+        <pre><code>a &amp; <em>b</em>
+        "c"</code></pre>
+        <p>The answer is <code><em>a&amp;b</em></code>.</p>
+        </article>
+        </main>
+    "#};
+
+    #[test]
+    fn test_parse_puzzle() {
+        assert_eq!(
+            parse_puzzle(PUZZLE),
+            [
+                PartExample {
+                    input: Some("3   4\n4   3\n".to_string()),
+                    answer: Some("11".to_string()),
+                },
+                PartExample {
+                    input: Some("a & b\n\"c\"\n".to_string()),
+                    answer: Some("a&b".to_string()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_template() {
+        let template = include_str!("../../template/rust/mod.rs");
+        let (updated, changes) = update_module(template, &parse_puzzle(PUZZLE)).unwrap();
+
+        assert!(updated.contains(concat!(
+            "    const EXAMPLE_INPUT: &str = indoc! {\"\n",
+            "        3   4\n",
+            "        4   3\n",
+            "    \"};\n",
+            "    const EXAMPLE_INPUT_PART_2: &str = indoc! {\"\n",
+            "        a & b\n",
+            "        \\\"c\\\"\n",
+            "    \"};\n",
+        )));
+        assert!(updated.contains("assert_eq!(part1(EXAMPLE_INPUT), 11);"));
+        assert!(updated.contains("assert_eq!(part2(EXAMPLE_INPUT_PART_2), \"a&b\");"));
+        assert!(updated.contains("assert_eq!(part1(include_str!(\"input.txt\")), 0);"));
+        assert_eq!(changes, [Change::Updated, Change::Added]);
+        assert_eq!(
+            update_module(&updated, &parse_puzzle(PUZZLE)),
+            Ok((updated, vec![Change::Unchanged, Change::Unchanged]))
+        );
+    }
+
+    #[test]
+    fn test_update_plain_string_example() {
+        let module = indoc! {r#"
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                const EXAMPLE_INPUT: &str =
+                    "1 2\n3 4\n";
+
+                #[test]
+                fn test_example_part1() {
+                    assert_eq!(part1(EXAMPLE_INPUT), 0);
+                }
+
+                const OTHER: &str = indoc! {r"
+                    a\b
+                "};
+            }
+        "#};
+        let parts = [PartExample {
+            input: Some("5 6\n".to_string()),
+            answer: Some("11".to_string()),
+        }];
+
+        let (updated, changes) = update_module(module, &parts).unwrap();
+        assert_eq!(changes, [Change::Updated]);
+        assert!(updated.contains(concat!(
+            "    const EXAMPLE_INPUT: &str = indoc! {\"\n",
+            "        5 6\n",
+            "    \"};\n",
+            "\n",
+            "    #[test]\n",
+        )));
+        assert!(updated.contains("assert_eq!(part1(EXAMPLE_INPUT), 11);"));
+        assert!(updated.contains("const OTHER"));
+
+        let unterminated = "const EXAMPLE_INPUT: &str = \"1\n";
+        assert_eq!(
+            update_module(unterminated, &parts),
+            Err("cannot find the end of `EXAMPLE_INPUT`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_parse_input_module() {
+        let module = indoc! {r#"
+            #[cfg(test)]
+            mod tests {
+                use indoc::indoc;
+
+                use super::*;
+
+                #[test]
+                fn test_example() {
+                    let (part1, part2) = parse_input(EXAMPLE_INPUT);
+
+                    assert_eq!(part1, 0);
+                    assert_eq!(
+                        part2,
+                        0
+                    );
+                }
+            }
+        "#};
+        let parts = [
+            PartExample {
+                input: Some("1\n".to_string()),
+                answer: Some("3".to_string()),
+            },
+            PartExample {
+                input: None,
+                answer: Some("7".to_string()),
+            },
+        ];
+
+        assert_eq!(
+            update_module(module, &parts).unwrap().0,
+            indoc! {r#"
+                #[cfg(test)]
+                mod tests {
+                    use indoc::indoc;
+
+                    use super::*;
+
+                    const EXAMPLE_INPUT: &str = indoc! {"
+                        1
+                    "};
+
+                    #[test]
+                    fn test_example() {
+                        let (part1, part2) = parse_input(EXAMPLE_INPUT);
+
+                        assert_eq!(part1, 3);
+                        assert_eq!(
+                            part2,
+                            7
+                        );
+                    }
+                }
+            "#}
+        );
+    }
+}
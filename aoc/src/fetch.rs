@@ -0,0 +1,212 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+pub const USER_AGENT: &str = "github.com/adriencaccia/aoc";
+
+/// Source of the puzzle inputs
+pub trait InputProvider {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// Inputs of the user logged in with `session` on the Advent of Code website, or a server mimicking it
+pub struct HttpProvider {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpProvider {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        HttpProvider {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+impl InputProvider for HttpProvider {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| format!("cannot download {}: {}", url, error))?
+            .into_string()
+            .map_err(|error| format!("cannot download {}: {}", url, error))
+    }
+}
+
+/// Inputs stored as `<dir>/<year>/<day>/input.txt`, only fetched from `provider` when missing
+pub struct CachedProvider<P> {
+    pub dir: PathBuf,
+    provider: P,
+}
+
+impl<P: InputProvider> CachedProvider<P> {
+    pub fn new(dir: PathBuf, provider: P) -> Self {
+        CachedProvider { dir, provider }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(day.to_string())
+            .join("input.txt")
+    }
+}
+
+impl<P: InputProvider> InputProvider for CachedProvider<P> {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.path(year, day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        let input = self.provider.fetch(year, day)?;
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            // written aside then renamed, so an interrupted download is never cached
+            let partial = path.with_extension("part");
+            fs::write(&partial, &input)?;
+            fs::rename(&partial, &path)
+        };
+        write().map_err(|error| format!("cannot cache {}: {}", path.display(), error))?;
+
+        Ok(input)
+    }
+}
+
+/// FNV-1a, stable across Rust versions unlike the `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Directory of the inputs of the user logged in with `session`, as everyone gets different inputs
+pub fn user_cache_dir(cache_dir: &Path, session: &str) -> PathBuf {
+    cache_dir.join(format!("{:016x}", fnv1a(session.trim().as_bytes())))
+}
+
+/// `aoc` in the cache directory of the platform, e.g. `~/.cache/aoc` on Linux
+pub fn default_cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("aoc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    /// Local server answering the input requests of `session`, recording the requested paths
+    fn stub_server(session: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let lines: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let path = lines[0].split(' ').nth(1).unwrap().to_string();
+                let logged_in = lines
+                    .iter()
+                    .any(|line| line.eq_ignore_ascii_case(&format!("cookie: session={}", session)));
+
+                let (status, body) = match path.strip_suffix("/input") {
+                    Some(_) if !logged_in => ("400 Bad Request", "Please log in".to_string()),
+                    Some(day) if !day.ends_with("/day/26") => {
+                        ("200 OK", format!("input of {}\n", day))
+                    }
+                    _ => ("404 Not Found", "404 Not Found".to_string()),
+                };
+                recorded.lock().unwrap().push(path);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_http_provider() {
+        let (url, requests) = stub_server("abc");
+
+        assert_eq!(
+            HttpProvider::new(&url, "abc\n").fetch(2024, 3),
+            Ok("input of /2024/day/3\n".to_string())
+        );
+        assert!(HttpProvider::new(&url, "abc").fetch(2024, 26).is_err());
+        assert!(HttpProvider::new(&url, "wrong").fetch(2024, 3).is_err());
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_cached_provider() {
+        let (url, requests) = stub_server("abc");
+        let dir = temp_dir("cache");
+        let provider = CachedProvider::new(dir.clone(), HttpProvider::new(&url, "abc"));
+
+        for _ in 0..2 {
+            assert_eq!(
+                provider.fetch(2025, 1),
+                Ok("input of /2025/day/1\n".to_string())
+            );
+        }
+        assert!(provider.fetch(2025, 26).is_err());
+        assert!(!provider.path(2025, 26).exists());
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["/2025/day/1/input", "/2025/day/26/input"]
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("2025/1/input.txt")).unwrap(),
+            "input of /2025/day/1\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_user_cache_dir() {
+        let cache = Path::new("cache");
+
+        assert_eq!(user_cache_dir(cache, "abc"), user_cache_dir(cache, "abc\n"));
+        assert_ne!(user_cache_dir(cache, "abc"), user_cache_dir(cache, "abd"));
+        assert_eq!(
+            user_cache_dir(cache, ""),
+            Path::new("cache/cbf29ce484222325")
+        );
+    }
+}
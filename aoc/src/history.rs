@@ -0,0 +1,366 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    report,
+    run::{DayRun, Status},
+};
+
+/// Differences under this duration are noise, whatever the threshold
+const NOISE: Duration = Duration::from_micros(1);
+
+/// Part of the record of a day solving both parts while parsing, timing the whole day
+pub const COMBINED: u8 = 3;
+
+/// Best time of a step of a day, measured at a commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Hash of the commit, suffixed with `-dirty` when the working tree had changes
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    /// `0` for the parsing of the input, or [`COMBINED`] for a whole combined day
+    pub part: u8,
+    pub nanos: u64,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+}
+
+/// Year, day and part of a record
+pub type Key = (u16, u8, u8);
+
+/// Records of the steps of `run` that succeeded
+pub fn records(run: &DayRun, commit: &str, time: u64) -> Vec<Record> {
+    let record = |part, nanos: u128| Record {
+        commit: commit.to_string(),
+        year: run.year,
+        day: run.day,
+        part,
+        nanos: nanos as u64,
+        time,
+    };
+
+    let mut records = vec![];
+    if !run.parse.is_empty() {
+        let part = if run.combined { COMBINED } else { 0 };
+        records.push(record(part, run.parse.min().as_nanos()));
+    }
+    records.extend(
+        run.parts
+            .iter()
+            .filter(|part| part.status == Status::Ok && !part.timings.is_empty())
+            .map(|part| record(part.part, part.timings.min().as_nanos())),
+    );
+    records
+}
+
+/// The commit checked out in `root`, to key the recorded times
+pub fn current_commit(root: &Path) -> Result<String, String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .map_err(|error| format!("cannot run git: {}", error))?;
+        if !output.status.success() {
+            return Err(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "HEAD"])?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+    Ok(if dirty {
+        format!("{}-dirty", commit)
+    } else {
+        commit
+    })
+}
+
+/// Every recorded time, stored as one JSON record per line
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Records stored at `path`, a missing file meaning none
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("cannot read {}: {}", path.display(), error)),
+        };
+        let records = content
+            .lines()
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|error| format!("invalid record in {}: {}", path.display(), error))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    pub fn append(&mut self, records: Vec<Record>) -> Result<(), String> {
+        let write = || {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            for record in &records {
+                writeln!(file, "{}", serde_json::to_string(record).unwrap())?;
+            }
+            Ok::<_, std::io::Error>(())
+        };
+        write().map_err(|error| format!("cannot write {}: {}", self.path.display(), error))?;
+
+        self.records.extend(records);
+        Ok(())
+    }
+
+    /// Commit of the last record
+    pub fn last_commit(&self) -> Option<&str> {
+        self.records.last().map(|record| record.commit.as_str())
+    }
+
+    /// Recorded commit matching `rev`, either a git revision or the prefix of a recorded commit
+    pub fn resolve(&self, rev: &str, root: &Path) -> Result<String, String> {
+        let recorded = |commit: &str| self.records.iter().any(|record| record.commit == commit);
+
+        let output = Command::new("git")
+            .args([
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{}^{{commit}}", rev),
+            ])
+            .current_dir(root)
+            .output();
+        if let Ok(output) = output {
+            let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if output.status.success() && recorded(&commit) {
+                return Ok(commit);
+            }
+        }
+
+        self.records
+            .iter()
+            .rev()
+            .map(|record| &record.commit)
+            .find(|commit| commit.starts_with(rev))
+            .cloned()
+            .ok_or_else(|| format!("no times recorded for {}", rev))
+    }
+
+    /// Best recorded time of each step at `commit`
+    pub fn best(&self, commit: &str) -> BTreeMap<Key, Duration> {
+        let mut best = BTreeMap::new();
+        for record in self.records.iter().filter(|record| record.commit == commit) {
+            let nanos = Duration::from_nanos(record.nanos);
+            best.entry((record.year, record.day, record.part))
+                .and_modify(|best: &mut Duration| *best = (*best).min(nanos))
+                .or_insert(nanos);
+        }
+        best
+    }
+}
+
+/// Table of the steps timed at both commits, and the number of steps slower than `baseline`
+/// by more than `threshold` percent
+pub fn compare(
+    baseline: &BTreeMap<Key, Duration>,
+    current: &BTreeMap<Key, Duration>,
+    threshold: f64,
+) -> (String, usize) {
+    let mut rows = vec![["Year", "Day", "Part", "Baseline", "Current", "Change"].map(String::from)];
+    let mut regressions = 0;
+
+    for (&(year, day, part), &before) in baseline {
+        let Some(&after) = current.get(&(year, day, part)) else {
+            continue;
+        };
+
+        let change =
+            (after.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0) * 100.0;
+        let regressed = change > threshold && after.saturating_sub(before) > NOISE;
+        regressions += usize::from(regressed);
+
+        rows.push([
+            year.to_string(),
+            day.to_string(),
+            match part {
+                0 => "parse".to_string(),
+                COMBINED => "both".to_string(),
+                part => part.to_string(),
+            },
+            report::format_duration(before),
+            report::format_duration(after),
+            format!(
+                "{:+.1}%{}",
+                change,
+                if regressed { " REGRESSED" } else { "" }
+            ),
+        ]);
+    }
+
+    let table = report::align(&rows, [false, false, false, false, false, true], &[1]);
+    (table, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::run::{PartRun, Timings};
+
+    fn timings(micros: u64) -> Timings {
+        let mut timings = Timings::default();
+        timings.push(Duration::from_micros(micros));
+        timings
+    }
+
+    fn history(name: &str) -> History {
+        let path =
+            std::env::temp_dir().join(format!("aoc-history-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        History::load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_records() {
+        let run = DayRun {
+            year: 2024,
+            day: 3,
+            combined: false,
+            parse: timings(5),
+            parse_allocations: None,
+            parts: vec![
+                PartRun {
+                    part: 1,
+                    status: Status::Ok,
+                    answer: Some("1".to_string()),
+                    verdict: None,
+                    error: None,
+                    timings: timings(20),
+                    allocations: None,
+                },
+                PartRun {
+                    part: 2,
+                    status: Status::Panic,
+                    answer: None,
+                    verdict: None,
+                    error: Some("oops".to_string()),
+                    timings: Timings::default(),
+                    allocations: None,
+                },
+            ],
+        };
+
+        let records = records(&run, "abc", 7);
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record.part, record.nanos))
+                .collect::<Vec<_>>(),
+            [(0, 5_000), (1, 20_000)]
+        );
+        assert!(records
+            .iter()
+            .all(|record| (record.commit.as_str(), record.time) == ("abc", 7)));
+
+        let combined = DayRun {
+            combined: true,
+            ..run
+        };
+        assert_eq!(super::records(&combined, "abc", 7)[0].part, COMBINED);
+    }
+
+    #[test]
+    fn test_history() {
+        let record = |commit: &str, part, nanos| Record {
+            commit: commit.to_string(),
+            year: 2025,
+            day: 1,
+            part,
+            nanos,
+            time: 0,
+        };
+        let mut history = history("store");
+        history
+            .append(vec![record("aaaa", 1, 300), record("aaaa", 1, 200)])
+            .unwrap();
+        history.append(vec![record("bbbb-dirty", 2, 50)]).unwrap();
+
+        let reloaded = History::load(&history.path).unwrap();
+        assert_eq!(reloaded.records, history.records);
+        assert_eq!(reloaded.last_commit(), Some("bbbb-dirty"));
+        assert_eq!(
+            reloaded.best("aaaa"),
+            BTreeMap::from([((2025, 1, 1), Duration::from_nanos(200))])
+        );
+        let root = std::env::temp_dir();
+        assert_eq!(reloaded.resolve("bb", &root), Ok("bbbb-dirty".to_string()));
+        assert!(reloaded.resolve("cc", &root).is_err());
+        fs::remove_file(&history.path).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let micros = |steps: &[(Key, u64)]| -> BTreeMap<Key, Duration> {
+            steps
+                .iter()
+                .map(|&(key, us)| (key, Duration::from_micros(us)))
+                .collect()
+        };
+        let baseline = micros(&[
+            ((2024, 1, 0), 10),
+            ((2024, 1, 1), 100),
+            ((2024, 1, 2), 1000),
+            ((2024, 2, 1), 5),
+            ((2024, 4, COMBINED), 50),
+        ]);
+        let current = micros(&[
+            ((2024, 1, 0), 1),
+            ((2024, 1, 1), 105),
+            ((2024, 1, 2), 1500),
+            ((2024, 3, 1), 5),
+            ((2024, 4, COMBINED), 50),
+        ]);
+
+        let (table, regressions) = compare(&baseline, &current, 10.0);
+        assert_eq!(regressions, 1);
+        assert_eq!(
+            table,
+            indoc! {"
+                Year | Day |  Part | Baseline |  Current | Change
+                -----+-----+-------+----------+----------+-----------------
+                2024 |   1 | parse |  10.00µs |   1.00µs | -90.0%
+                2024 |   1 |     1 | 100.00µs | 105.00µs | +5.0%
+                2024 |   1 |     2 |   1.00ms |   1.50ms | +50.0% REGRESSED
+                2024 |   4 |  both |  50.00µs |  50.00µs | +0.0%
+            "}
+        );
+        assert_eq!(compare(&baseline, &current, 60.0).1, 0);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Registration;
+
+/// Where the puzzle inputs are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `input.txt` next to each day module
+    Crate,
+    /// `<dir>/<year>/<day>/input.txt`
+    Dir(PathBuf),
+    /// A single input file, for a single day
+    File(PathBuf),
+    /// The standard input, for a single day
+    Stdin,
+}
+
+impl InputSource {
+    /// `input` takes precedence over `inputs_dir`, `-` being the standard input
+    pub fn new(input: Option<PathBuf>, inputs_dir: Option<PathBuf>) -> Self {
+        match (input, inputs_dir) {
+            (Some(input), _) if input == Path::new("-") => InputSource::Stdin,
+            (Some(input), _) => InputSource::File(input),
+            (None, Some(dir)) => InputSource::Dir(dir),
+            (None, None) => InputSource::Crate,
+        }
+    }
+
+    /// Whether the same input is used for every day
+    pub fn is_single(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Stdin)
+    }
+
+    pub fn path(&self, day: &Registration) -> Option<PathBuf> {
+        match self {
+            InputSource::Crate => Some(day.input_path()),
+            InputSource::Dir(dir) => Some(
+                dir.join(day.year.to_string())
+                    .join(day.day.to_string())
+                    .join("input.txt"),
+            ),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: &Registration) -> Result<String, MissingInput> {
+        let path = self.path(day);
+        let input = match &path {
+            Some(path) => std::fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        input.map_err(|error| MissingInput {
+            year: day.year,
+            day: day.day,
+            path,
+            error,
+        })
+    }
+}
+
+/// The `answers.toml` holding the answers to the inputs of the year of `day`, in `inputs_dir` if
+/// set or in the year crate otherwise, wherever the inputs are read from
+pub fn answers_path(inputs_dir: Option<&Path>, day: &Registration) -> PathBuf {
+    match inputs_dir {
+        Some(dir) => dir.join(day.year.to_string()).join("answers.toml"),
+        None => Path::new(day.manifest_dir).join("answers.toml"),
+    }
+}
+
+/// The input of a day could not be read
+#[derive(Debug)]
+pub struct MissingInput {
+    pub year: u16,
+    pub day: u8,
+    /// `None` for the standard input
+    pub path: Option<PathBuf>,
+    pub error: io::Error,
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing input for {} day {}: ", self.year, self.day)?;
+        match &self.path {
+            Some(path) => write!(f, "cannot read {}: {}", path.display(), self.error),
+            None => write!(f, "cannot read the standard input: {}", self.error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registration() -> &'static Registration {
+        rust_2024::days()[2]
+    }
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::new(None, None), InputSource::Crate);
+        assert_eq!(
+            InputSource::new(Some("-".into()), Some("inputs".into())),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::new(None, Some("inputs".into())),
+            InputSource::Dir("inputs".into())
+        );
+    }
+
+    #[test]
+    fn test_dir_path() {
+        let source = InputSource::Dir("inputs".into());
+
+        assert_eq!(
+            source.path(registration()),
+            Some(PathBuf::from("inputs/2024/3/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_answers_path() {
+        assert_eq!(
+            answers_path(Some(Path::new("inputs")), registration()),
+            PathBuf::from("inputs/2024/answers.toml")
+        );
+        assert!(answers_path(None, registration()).ends_with("2024/answers.toml"));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let source = InputSource::Dir("does/not/exist".into());
+        let error = source.read(registration()).unwrap_err();
+
+        assert!(error.to_string().starts_with(
+            "missing input for 2024 day 3: cannot read does/not/exist/2024/3/input.txt"
+        ));
+    }
+}
@@ -0,0 +1,729 @@
+mod alloc;
+mod answers;
+mod check;
+mod corpus;
+mod examples;
+mod fetch;
+mod history;
+mod html;
+mod input;
+mod report;
+mod run;
+mod scaffold;
+mod selection;
+mod submit;
+mod worker;
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use answers::AnswerStore;
+use aoc_common::Registration;
+use clap::{Parser, Subcommand};
+use fetch::{CachedProvider, HttpProvider, InputProvider};
+use input::InputSource;
+use rayon::prelude::*;
+use report::Format;
+use run::{DayRun, PartRun};
+use scaffold::Lang;
+use selection::{Ranges, Selector};
+use worker::Limits;
+
+/// Run the Advent of Code solutions of any year and day
+#[derive(Parser, Debug)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Days to run, e.g. `2024:1-10,17` or `2023`, if not specified all days will be run
+    pub selectors: Vec<Selector>,
+
+    /// Only run these years, e.g. `2023-2024`
+    #[arg(short, long)]
+    pub year: Option<Ranges>,
+
+    /// Only run these days, e.g. `1-10,17`
+    #[arg(short, long)]
+    pub day: Option<Ranges>,
+
+    /// Part to run, if not specified both parts will be run
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Input file to use instead of the stored one, `-` for the standard input, for a single day
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Directory containing the inputs as `<year>/<day>/input.txt`
+    #[arg(long, env = "AOC_INPUTS")]
+    pub inputs: Option<PathBuf>,
+
+    /// Download the missing inputs into the cache and read the inputs from it, instead of `--inputs`
+    #[arg(long, conflicts_with = "input")]
+    pub fetch: bool,
+
+    /// Session cookie of the Advent of Code website, to download the inputs
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Website to download the inputs from
+    #[arg(long, global = true, env = "AOC_URL", default_value = fetch::AOC_URL)]
+    pub aoc_url: String,
+
+    /// Directory caching the downloaded inputs of each user and the recorded times, `aoc` in the user cache directory by default
+    #[arg(long, global = true, env = "AOC_CACHE_DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Number of times to run each day, reporting the min and median durations
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    pub repeat: u16,
+
+    /// Format of the report printed once all the days ran
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Check the answers against the `answers.toml` of each year
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Append the best time of each step to the history in the cache directory, keyed by the current commit
+    #[arg(long, conflicts_with = "input")]
+    pub record: bool,
+
+    /// Wall-clock limit in seconds of each part, including parsing and repetitions
+    #[arg(long, value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Memory limit in MiB of each part
+    #[arg(long)]
+    pub memory_limit: Option<u64>,
+
+    /// Number of days to run at once, timings are less reliable with more than one
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    pub jobs: u16,
+
+    /// Count the allocations, allocated bytes and peak heap of each parse and part, running each of them once more, needs the `allocations` feature
+    #[arg(long)]
+    pub allocations: bool,
+
+    /// Run each day on a single thread, disabling the parallelism inside days for cleaner timings
+    #[arg(long)]
+    pub single_threaded: bool,
+
+    /// Run the selected day and print the run as JSON, used to run parts in a separate process
+    #[arg(long, hide = true)]
+    pub worker: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create a day from the template and declare it in its year crate, existing files are kept
+    New {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Language of the day, a TypeScript day is not declared anywhere
+        #[arg(short, long, value_enum, default_value_t = Lang::Rust)]
+        lang: Lang,
+    },
+    /// Update the example inputs and tests of a day from its puzzle description saved as HTML
+    Examples {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle page, once part 2 is unlocked to get the examples of both parts
+        puzzle: PathBuf,
+    },
+    /// Solve a part on the input of the session user and submit the answer, unless it is known to be wrong
+    Submit {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Solve the selected days on every input of the corpus, checking the paired answers
+    Corpus {
+        /// Days to run, e.g. `2024:1-10,17` or `2023`, if not specified all days will be run
+        selectors: Vec<Selector>,
+
+        /// Directory containing the inputs as `<year>/<day>/<name>.txt`, `inputs` by default
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+    /// Cross-check the selected days against their reference solvers on random inputs
+    Check {
+        /// Days to check, e.g. `2024:1-10,17` or `2023`, if not specified all days with a generator are checked
+        selectors: Vec<Selector>,
+
+        /// Number of random inputs per day
+        #[arg(long, default_value_t = 100)]
+        cases: u64,
+
+        /// Size of the largest random input, the meaning of a size depends on the day
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+    },
+    /// Compare the times recorded with `--record` at a commit to the ones of a baseline commit
+    History {
+        /// Commit to compare to, e.g. `main` or the prefix of a recorded commit
+        #[arg(long)]
+        baseline: String,
+
+        /// Commit to compare, the last one recorded by default
+        #[arg(long)]
+        commit: Option<String>,
+
+        /// Percentage by which a step must be slower than the baseline to be a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    s.parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("invalid number of seconds `{}`", s))
+}
+
+impl Args {
+    fn is_selected(&self, day: &Registration) -> bool {
+        (self.selectors.is_empty()
+            || self
+                .selectors
+                .iter()
+                .any(|selector| selector.matches(day.year, day.day)))
+            && self
+                .year
+                .as_ref()
+                .is_none_or(|year| year.contains(day.year))
+            && self
+                .day
+                .as_ref()
+                .is_none_or(|days| days.contains(day.day.into()))
+    }
+
+    fn cache_dir(&self) -> Result<PathBuf, String> {
+        self.cache_dir
+            .clone()
+            .or_else(fetch::default_cache_dir)
+            .ok_or_else(|| {
+                "cannot find the cache directory, set AOC_CACHE_DIR or --cache-dir".to_string()
+            })
+    }
+
+    fn history(&self) -> Result<history::History, String> {
+        history::History::load(&self.cache_dir()?.join("history.jsonl"))
+    }
+
+    fn input_provider(&self) -> Result<CachedProvider<HttpProvider>, String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or("a session is required to download inputs, set AOC_SESSION or --session")?;
+
+        Ok(CachedProvider::new(
+            fetch::user_cache_dir(&self.cache_dir()?, session),
+            HttpProvider::new(&self.aoc_url, session),
+        ))
+    }
+
+    pub fn limits(&self) -> Limits {
+        Limits {
+            timeout: self.timeout,
+            memory: self.memory_limit,
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// Every day of every year, sorted by year then day
+fn registrations() -> Vec<&'static Registration> {
+    [rust_2023::days(), rust_2024::days(), rust_2025::days()].concat()
+}
+
+/// Run `f` on a single thread, parallel iterators included
+fn single_threaded<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+fn run_day(day: &Registration, args: &Args, source: &InputSource) -> DayRun {
+    eprintln!("Running {} day {}", day.year, day.day);
+
+    match source.read(day) {
+        Ok(input) if args.limits().is_set() => worker::run_day(day, &input, &args.parts(), args),
+        Ok(input) => run::run_day(day, &input, &args.parts(), args.repeat.into()),
+        Err(error) => {
+            eprintln!("{}", error);
+            DayRun::missing_input(day, &args.parts(), error.to_string())
+        }
+    }
+}
+
+fn exit_with_error(error: String) -> ! {
+    eprintln!("{}", error);
+    std::process::exit(1);
+}
+
+/// Scaffold the day, then download its input if a session is set and the input is empty
+fn new_day(year: u16, day: u8, lang: Lang, args: &Args) {
+    let root = scaffold::workspace_root().unwrap_or_else(|error| exit_with_error(error));
+    match scaffold::new_day(&root, year, day, lang) {
+        Ok(actions) if actions.is_empty() => println!("{} day {} already exists", year, day),
+        Ok(actions) => actions.iter().for_each(|action| println!("{}", action)),
+        Err(error) => exit_with_error(error),
+    }
+
+    let path = scaffold::module_dir(&root, year, day).join("input.txt");
+    if args.session.is_none()
+        || !std::fs::read_to_string(&path)
+            .unwrap_or_default()
+            .is_empty()
+    {
+        return;
+    }
+    let input = args
+        .input_provider()
+        .and_then(|provider| provider.fetch(year, day))
+        .unwrap_or_else(|error| exit_with_error(error));
+    std::fs::write(&path, input).unwrap_or_else(|error| {
+        exit_with_error(format!("cannot write {}: {}", path.display(), error))
+    });
+    println!("downloaded the input to {}", path.display());
+}
+
+fn find_day(year: u16, day: u8) -> &'static Registration {
+    registrations()
+        .into_iter()
+        .find(|registration| (registration.year, registration.day) == (year, day))
+        .unwrap_or_else(|| exit_with_error(format!("there is no module for {} day {}", year, day)))
+}
+
+/// Write the examples of the puzzle saved at `puzzle` into the module of the day
+fn update_examples(year: u16, day: u8, puzzle: &Path) {
+    let registration = find_day(year, day);
+    let html = std::fs::read_to_string(puzzle).unwrap_or_else(|error| {
+        exit_with_error(format!("cannot read {}: {}", puzzle.display(), error))
+    });
+    let parts = examples::parse_puzzle(&html);
+    if parts.is_empty() {
+        exit_with_error(format!("no puzzle description in {}", puzzle.display()));
+    }
+
+    let root = scaffold::workspace_root().unwrap_or_else(|error| exit_with_error(error));
+    let path = root
+        .join(year.to_string())
+        .join("src")
+        .join(registration.name())
+        .join("mod.rs");
+    let source = std::fs::read_to_string(&path).unwrap_or_else(|error| {
+        exit_with_error(format!("cannot read {}: {}", path.display(), error))
+    });
+    let (updated, changes) = examples::update_module(&source, &parts)
+        .unwrap_or_else(|error| exit_with_error(format!("{}: {}", path.display(), error)));
+    std::fs::write(&path, updated).unwrap_or_else(|error| {
+        exit_with_error(format!("cannot write {}: {}", path.display(), error))
+    });
+
+    for (part, (example, change)) in (1..).zip(parts.iter().zip(changes)) {
+        println!(
+            "part {}: {} example, expected answer {}",
+            part,
+            change.as_str(),
+            example.answer.as_deref().unwrap_or("not found")
+        );
+    }
+}
+
+/// Solve `part` of the day on the input of the session user, then submit the answer
+fn submit_answer(year: u16, day: u8, part: u8, args: &Args) {
+    let registration = find_day(year, day);
+    if part > registration.parts {
+        exit_with_error(format!("{} day {} has no part {}", year, day, part));
+    }
+    let provider = args
+        .input_provider()
+        .unwrap_or_else(|error| exit_with_error(error));
+    let input = provider
+        .fetch(year, day)
+        .unwrap_or_else(|error| exit_with_error(error));
+
+    run::install_panic_hook();
+    let run = run::run_day(registration, &input, &[part], 1);
+    let answer = match &run.parts[0] {
+        PartRun {
+            answer: Some(answer),
+            ..
+        } => answer,
+        PartRun { error, .. } => exit_with_error(format!(
+            "{} day {} part {} failed: {}",
+            year,
+            day,
+            part,
+            error.as_deref().unwrap_or_default()
+        )),
+    };
+    println!("{} day {} part {}: {}", year, day, part, answer);
+
+    let mut history = submit::History::load(&provider.dir.join("guesses.jsonl"))
+        .unwrap_or_else(|error| exit_with_error(error));
+    let client = submit::HttpClient::new(&args.aoc_url, args.session.as_deref().unwrap());
+    let reply = submit::submit(&client, &mut history, year, day, part, answer)
+        .unwrap_or_else(|error| exit_with_error(error));
+
+    println!("{}: {}", reply.response.as_str(), reply.message);
+    if reply.response != submit::Response::Correct {
+        std::process::exit(1);
+    }
+}
+
+fn selected_days(selectors: &[Selector]) -> Vec<&'static Registration> {
+    registrations()
+        .into_iter()
+        .filter(|day| {
+            selectors.is_empty()
+                || selectors
+                    .iter()
+                    .any(|selector| selector.matches(day.year, day.day))
+        })
+        .collect()
+}
+
+/// Run the corpus, each part in a worker when limits are set
+fn run_corpus(selectors: &[Selector], dir: Option<&Path>, args: &Args) {
+    let dir = match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => scaffold::workspace_root()
+            .unwrap_or_else(|error| exit_with_error(error))
+            .join("inputs"),
+    };
+    let cases = corpus::load(&dir).unwrap_or_else(|error| exit_with_error(error));
+    let days = selected_days(selectors);
+
+    run::install_panic_hook();
+    let solve = |day: &Registration, input: &str, parts: &[u8]| {
+        if args.limits().is_set() {
+            worker::run_day(day, input, parts, args)
+        } else {
+            run::run_day(day, input, parts, 1)
+        }
+    };
+    let (table, failures) = corpus::run(&cases, &days, &solve);
+    print!("{}", table);
+    if !failures.is_empty() {
+        exit_with_error(format!("{} inputs failed", failures.len()));
+    }
+}
+
+fn run_check(selectors: &[Selector], cases: u64, size: usize) {
+    if !cfg!(feature = "check") {
+        exit_with_error("check needs the runner built with `--features check`".to_string());
+    }
+    let days: Vec<_> = selected_days(selectors)
+        .into_iter()
+        .filter_map(|day| Some((day, check::generator(day)?)))
+        .collect();
+    if days.is_empty() {
+        exit_with_error("No selected day has a generator".to_string());
+    }
+
+    run::install_panic_hook();
+    let mut failures = 0;
+    for (day, generator) in days {
+        match check::check(day, generator, cases, size) {
+            Ok(()) => println!("{} day {}: {} inputs ok", day.year, day.day, cases),
+            Err(mismatch) => {
+                println!("{}", mismatch);
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        exit_with_error(format!("{} days failed", failures));
+    }
+}
+
+/// Report the steps of `commit` slower than at `baseline` by more than `threshold` percent
+fn compare_history(baseline: &str, commit: Option<&str>, threshold: f64, args: &Args) {
+    let root = scaffold::workspace_root().unwrap_or_else(|error| exit_with_error(error));
+    let history = args
+        .history()
+        .unwrap_or_else(|error| exit_with_error(error));
+    let commit = match commit.or(history.last_commit()) {
+        Some(commit) => history.resolve(commit, &root),
+        None => Err("no times recorded, run with --record first".to_string()),
+    };
+    let commit = commit.unwrap_or_else(|error| exit_with_error(error));
+    let baseline = history
+        .resolve(baseline, &root)
+        .unwrap_or_else(|error| exit_with_error(error));
+
+    println!("{} compared to {}", commit, baseline);
+    let (table, regressions) =
+        history::compare(&history.best(&baseline), &history.best(&commit), threshold);
+    print!("{}", table);
+    if regressions > 0 {
+        exit_with_error(format!("{} steps regressed", regressions));
+    }
+}
+
+/// Append the times of `runs` to the history, keyed by the current commit
+fn record_runs(runs: &[DayRun], args: &Args) -> Result<(), String> {
+    let commit = history::current_commit(&scaffold::workspace_root()?)?;
+    let time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let records = runs
+        .iter()
+        .flat_map(|run| history::records(run, &commit, time))
+        .collect();
+
+    args.history()?.append(records)?;
+    eprintln!("Recorded the times of {}", commit);
+    Ok(())
+}
+
+/// Known answers of the years of `days`.
+///
+/// Fetched inputs are those of the session user, so their answers are the ones stored in the
+/// cache directory of the user, along with the answers submitted as correct, rather than the
+/// answers to the inputs of `--inputs` or of the year crates.
+fn load_answers(args: &Args, days: &[&Registration]) -> Result<AnswerStore, String> {
+    let user_dir = if args.fetch {
+        Some(args.input_provider()?.dir)
+    } else {
+        None
+    };
+    let inputs_dir = user_dir.as_deref().or(args.inputs.as_deref());
+
+    let mut answers = AnswerStore::default();
+    let mut years = days.to_vec();
+    years.dedup_by_key(|day| day.year);
+    for day in years {
+        let path = input::answers_path(inputs_dir, day);
+        answers.load(day.year, &path)?;
+    }
+
+    if let Some(dir) = user_dir {
+        let guesses = submit::History::load(&dir.join("guesses.jsonl"))?;
+        for guess in guesses.correct() {
+            answers.insert(guess.year, guess.day, guess.part, guess.answer.clone());
+        }
+    }
+    Ok(answers)
+}
+
+fn main() {
+    let args = Args::parse();
+    match &args.command {
+        Some(Command::New { year, day, lang }) => return new_day(*year, *day, *lang, &args),
+        Some(Command::Examples { year, day, puzzle }) => {
+            return update_examples(*year, *day, puzzle)
+        }
+        Some(Command::Submit { year, day, part }) => {
+            return submit_answer(*year, *day, *part, &args)
+        }
+        Some(Command::Corpus { selectors, dir }) => {
+            return run_corpus(selectors, dir.as_deref(), &args)
+        }
+        Some(Command::Check {
+            selectors,
+            cases,
+            size,
+        }) => return run_check(selectors, *cases, *size),
+        Some(Command::History {
+            baseline,
+            commit,
+            threshold,
+        }) => return compare_history(baseline, commit.as_deref(), *threshold, &args),
+        None => {}
+    }
+    run::install_panic_hook();
+    if args.allocations {
+        if !cfg!(feature = "allocations") {
+            exit_with_error(
+                "--allocations needs the runner built with `--features allocations`".to_string(),
+            );
+        }
+        if args.jobs > 1 {
+            exit_with_error("--allocations cannot count the days run with --jobs".to_string());
+        }
+        alloc::ALLOCATOR.enable();
+    }
+
+    let days: Vec<_> = registrations()
+        .into_iter()
+        .filter(|day| args.is_selected(day))
+        .collect();
+    if days.is_empty() {
+        eprintln!("No day matches the selection");
+        std::process::exit(1);
+    }
+
+    let source = if args.fetch {
+        let provider = args
+            .input_provider()
+            .unwrap_or_else(|error| exit_with_error(error));
+        for day in &days {
+            // a failed download is reported as a missing input when running the day
+            if let Err(error) = provider.fetch(day.year, day.day) {
+                eprintln!("{}", error);
+            }
+        }
+        InputSource::Dir(provider.dir)
+    } else {
+        InputSource::new(args.input.clone(), args.inputs.clone())
+    };
+    if source.is_single() && days.len() > 1 {
+        eprintln!(
+            "--input can only be used with a single day, {} are selected",
+            days.len()
+        );
+        std::process::exit(1);
+    }
+
+    if args.worker {
+        let input = source.read(days[0]).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        });
+        if let Some(memory) = args.memory_limit {
+            if let Err(error) = worker::limit_memory(memory) {
+                eprintln!("cannot limit memory: {}", error);
+                std::process::exit(1);
+            }
+        }
+        let serve = || worker::serve(days[0], &input, &args.parts(), args.repeat);
+        if args.single_threaded {
+            single_threaded(serve);
+        } else {
+            serve();
+        }
+        return;
+    }
+
+    let mut answers = AnswerStore::default();
+    if args.verify {
+        if source.is_single() {
+            eprintln!("--verify cannot be used with --input");
+            std::process::exit(1);
+        }
+        answers = load_answers(&args, &days).unwrap_or_else(|error| exit_with_error(error));
+    }
+
+    let run = |day: &&Registration| {
+        if args.single_threaded {
+            single_threaded(|| run_day(day, &args, &source))
+        } else {
+            run_day(day, &args, &source)
+        }
+    };
+    // days are collected in order whatever order they finish in
+    let mut runs: Vec<DayRun> = if args.jobs > 1 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(args.jobs.into())
+            .build()
+            .unwrap()
+            .install(|| days.par_iter().map(run).collect())
+    } else {
+        days.iter().map(run).collect()
+    };
+    if args.verify {
+        runs.iter_mut().for_each(|run| answers.verify(run));
+    }
+
+    print!("{}", args.format.report(&runs, args.repeat.into()));
+    if args.record {
+        record_runs(&runs, &args).unwrap_or_else(|error| exit_with_error(error));
+    }
+    if !runs.iter().all(DayRun::is_ok) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_answers_when_fetching() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("aoc-fetched-answers-{}", std::process::id()));
+        let user_dir = fetch::user_cache_dir(&cache_dir, "abc");
+        let mut guesses = submit::History::load(&user_dir.join("guesses.jsonl")).unwrap();
+        for (part, answer, response) in [
+            (1, "42", submit::Response::Correct),
+            (2, "7", submit::Response::TooLow),
+        ] {
+            guesses
+                .record(submit::Guess {
+                    year: 2025,
+                    day: 1,
+                    part,
+                    answer: answer.to_string(),
+                    response,
+                    time: 0,
+                })
+                .unwrap();
+        }
+        let args = Args::parse_from([
+            "aoc",
+            "2025:1",
+            "--fetch",
+            "--verify",
+            "--session",
+            "abc",
+            "--cache-dir",
+            cache_dir.to_str().unwrap(),
+        ]);
+        let days: Vec<_> = registrations()
+            .into_iter()
+            .filter(|day| args.is_selected(day))
+            .collect();
+
+        // the answers of the year crate are those of another user
+        let answers = load_answers(&args, &days).unwrap();
+        assert_eq!(answers.expected(2025, 1, 1), Some("42"));
+        assert_eq!(answers.expected(2025, 1, 2), None);
+
+        std::fs::create_dir_all(user_dir.join("2025")).unwrap();
+        std::fs::write(user_dir.join("2025/answers.toml"), "[1]\npart2 = 8\n").unwrap();
+        let answers = load_answers(&args, &days).unwrap();
+        assert_eq!(answers.expected(2025, 1, 2), Some("8"));
+
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_single_threaded() {
+        assert_eq!(single_threaded(rayon::current_num_threads), 1);
+        assert_eq!(
+            single_threaded(|| (0..100).into_par_iter().sum::<u32>()),
+            4950
+        );
+    }
+}
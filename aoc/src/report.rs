@@ -0,0 +1,548 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    alloc::Allocations,
+    answers::Verdict,
+    run::{DayRun, PartRun, Status, Timings},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned table
+    Text,
+    /// Array of records, one per day and part
+    Json,
+    /// One record per line for each day and part
+    Csv,
+}
+
+impl Format {
+    pub fn report(self, runs: &[DayRun], repeat: usize) -> String {
+        match self {
+            Format::Text => table(runs, repeat) + &allocations_table(runs),
+            Format::Json => json(runs),
+            Format::Csv => csv(runs),
+        }
+    }
+}
+
+const HEADER: [&str; 8] = [
+    "Year", "Day", "Answer 1", "Answer 2", "Parse", "Time 1", "Time 2", "Total",
+];
+/// Columns aligned to the left, the others are numbers aligned to the right
+const LEFT_ALIGNED: [bool; 8] = [false, false, true, true, false, false, false, false];
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// `min` when the days ran once, `min / median` otherwise
+fn format_timings(timings: &Timings, repeat: usize) -> String {
+    if timings.is_empty() {
+        "-".to_string()
+    } else if repeat > 1 {
+        format!(
+            "{} / {}",
+            format_duration(timings.min()),
+            format_duration(timings.median())
+        )
+    } else {
+        format_duration(timings.min())
+    }
+}
+
+fn format_total(total: (Duration, Duration), repeat: usize) -> String {
+    if repeat > 1 {
+        format!(
+            "{} / {}",
+            format_duration(total.0),
+            format_duration(total.1)
+        )
+    } else {
+        format_duration(total.0)
+    }
+}
+
+/// Answer of `part` and its verdict, or why it could not be solved
+pub fn answer_cell(run: &DayRun, part: u8) -> String {
+    match run.part(part) {
+        Some(PartRun {
+            answer: Some(answer),
+            verdict,
+            ..
+        }) => match verdict {
+            None => answer.clone(),
+            Some(Verdict::Wrong { expected }) => {
+                format!("{} WRONG, expected {}", answer, expected)
+            }
+            Some(verdict) => format!("{} {}", answer, verdict.as_str().to_uppercase()),
+        },
+        Some(run) => run.status.as_str().replace('_', " "),
+        None => "-".to_string(),
+    }
+}
+
+/// Timings of a combined day are only shown in its total, as its parts are solved while parsing
+fn row(run: &DayRun, repeat: usize) -> [String; 8] {
+    let time = |part| match run.part(part) {
+        None => "-".to_string(),
+        Some(_) if run.combined => "combined".to_string(),
+        Some(part) => format_timings(&part.timings, repeat),
+    };
+
+    [
+        run.year.to_string(),
+        run.day.to_string(),
+        answer_cell(run, 1),
+        answer_cell(run, 2),
+        if run.combined {
+            "-".to_string()
+        } else {
+            format_timings(&run.parse, repeat)
+        },
+        time(1),
+        time(2),
+        format_total(
+            (run.total(Timings::min), run.total(Timings::median)),
+            repeat,
+        ),
+    ]
+}
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// Whether both parts are solved while parsing, only the total being timed
+    combined: bool,
+    status: Status,
+    answer: Option<&'a str>,
+    /// `None` when the answer was not verified
+    verdict: Option<&'static str>,
+    expected: Option<&'a str>,
+    /// Why the part could not be solved
+    error: Option<&'a str>,
+    parse_ns: Option<u64>,
+    /// Min duration of the part
+    duration_ns: Option<u64>,
+    median_ns: Option<u64>,
+    /// Min duration of the whole day
+    total_ns: Option<u64>,
+    /// Only when the allocations were counted
+    #[serde(flatten)]
+    parse_allocations: Option<ParseAllocations>,
+    #[serde(flatten)]
+    allocations: Option<Allocations>,
+}
+
+#[derive(Debug, Serialize)]
+struct ParseAllocations {
+    parse_count: u64,
+    parse_bytes: u64,
+    parse_peak: u64,
+}
+
+fn nanos(timings: &Timings, stat: fn(&Timings) -> Duration) -> Option<u64> {
+    (!timings.is_empty()).then(|| stat(timings).as_nanos() as u64)
+}
+
+fn records(runs: &[DayRun]) -> Vec<Record<'_>> {
+    runs.iter()
+        .flat_map(|run| {
+            run.parts.iter().map(|part| Record {
+                year: run.year,
+                day: run.day,
+                part: part.part,
+                combined: run.combined,
+                status: part.status,
+                answer: part.answer.as_deref(),
+                verdict: part.verdict.as_ref().map(Verdict::as_str),
+                expected: match &part.verdict {
+                    Some(Verdict::Wrong { expected }) => Some(expected),
+                    _ => None,
+                },
+                error: part.error.as_deref(),
+                parse_ns: nanos(&run.parse, Timings::min).filter(|_| !run.combined),
+                duration_ns: nanos(&part.timings, Timings::min),
+                median_ns: nanos(&part.timings, Timings::median),
+                total_ns: (!run.parse.is_empty())
+                    .then(|| run.total(Timings::min).as_nanos() as u64),
+                parse_allocations: run.parse_allocations.map(|allocations| ParseAllocations {
+                    parse_count: allocations.count,
+                    parse_bytes: allocations.bytes,
+                    parse_peak: allocations.peak,
+                }),
+                allocations: part.allocations,
+            })
+        })
+        .collect()
+}
+
+pub fn json(runs: &[DayRun]) -> String {
+    serde_json::to_string_pretty(&records(runs)).unwrap() + "\n"
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn csv(runs: &[DayRun]) -> String {
+    let optional = |value: Option<u64>| value.map_or(String::new(), |value| value.to_string());
+
+    let mut csv = String::from(
+        "year,day,part,combined,status,answer,verdict,expected,error,parse_ns,duration_ns,\
+        median_ns,total_ns,parse_count,parse_bytes,parse_peak,count,bytes,peak\n",
+    );
+    for record in records(runs) {
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record.combined.to_string(),
+            record.status.as_str().to_string(),
+            csv_field(record.answer.unwrap_or_default()),
+            record.verdict.unwrap_or_default().to_string(),
+            csv_field(record.expected.unwrap_or_default()),
+            csv_field(record.error.unwrap_or_default()),
+            optional(record.parse_ns),
+            optional(record.duration_ns),
+            optional(record.median_ns),
+            optional(record.total_ns),
+            optional(record.parse_allocations.as_ref().map(|a| a.parse_count)),
+            optional(record.parse_allocations.as_ref().map(|a| a.parse_bytes)),
+            optional(record.parse_allocations.as_ref().map(|a| a.parse_peak)),
+            optional(record.allocations.map(|a| a.count)),
+            optional(record.allocations.map(|a| a.bytes)),
+            optional(record.allocations.map(|a| a.peak)),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Aligned table of the answers and timings of `runs`, ending with the grand total
+pub fn table(runs: &[DayRun], repeat: usize) -> String {
+    let total = runs
+        .iter()
+        .fold((Duration::ZERO, Duration::ZERO), |acc, run| {
+            (
+                acc.0 + run.total(Timings::min),
+                acc.1 + run.total(Timings::median),
+            )
+        });
+
+    let mut rows = vec![HEADER.map(String::from)];
+    rows.extend(runs.iter().map(|run| row(run, repeat)));
+    let mut total_row: [String; 8] = Default::default();
+    total_row[0] = "Total".to_string();
+    total_row[7] = format_total(total, repeat);
+    rows.push(total_row);
+
+    let separators = [1, rows.len() - 1];
+    align(&rows, LEFT_ALIGNED, &separators)
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{}B", bytes),
+        1024..1048576 => format!("{:.2}KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.2}MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+/// Aligned table of the allocations of each step of `runs`, empty if they were not counted
+pub fn allocations_table(runs: &[DayRun]) -> String {
+    let mut rows = vec![["Year", "Day", "Step", "Allocations", "Bytes", "Peak"].map(String::from)];
+    for run in runs {
+        let parse = if run.combined { "both parts" } else { "parse" };
+        let steps = std::iter::once((parse.to_string(), run.parse_allocations)).chain(
+            run.parts
+                .iter()
+                .map(|part| (format!("part {}", part.part), part.allocations)),
+        );
+        for (step, allocations) in steps {
+            let Some(allocations) = allocations else {
+                continue;
+            };
+            rows.push([
+                run.year.to_string(),
+                run.day.to_string(),
+                step,
+                allocations.count.to_string(),
+                format_bytes(allocations.bytes),
+                format_bytes(allocations.peak),
+            ]);
+        }
+    }
+
+    if rows.len() == 1 {
+        return String::new();
+    }
+    format!(
+        "\n{}",
+        align(&rows, [false, false, true, false, false, false], &[1])
+    )
+}
+
+/// `rows` aligned in columns, with a separator line before each row index of `separators`
+pub fn align<const N: usize>(
+    rows: &[[String; N]],
+    left_aligned: [bool; N],
+    separators: &[usize],
+) -> String {
+    let widths: Vec<usize> = (0..N)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut table = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        if separators.contains(&i) {
+            let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+            table.push_str(separator.join("-+-").trim_end());
+            table.push('\n');
+        }
+
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .zip(left_aligned)
+            .map(|((cell, &width), left)| {
+                if left {
+                    format!("{:<width$}", cell)
+                } else {
+                    format!("{:>width$}", cell)
+                }
+            })
+            .collect();
+        table.push_str(cells.join(" | ").trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn timings(micros: &[u64]) -> Timings {
+        let mut timings = Timings::default();
+        for &us in micros {
+            timings.push(Duration::from_micros(us));
+        }
+        timings
+    }
+
+    fn runs() -> Vec<DayRun> {
+        vec![
+            DayRun {
+                year: 2024,
+                day: 1,
+                combined: false,
+                parse: timings(&[10, 20, 30]),
+                parse_allocations: None,
+                parts: vec![
+                    PartRun {
+                        part: 1,
+                        status: Status::Ok,
+                        answer: Some("42".to_string()),
+                        verdict: None,
+                        error: None,
+                        timings: timings(&[100, 200, 300]),
+                        allocations: None,
+                    },
+                    PartRun {
+                        part: 2,
+                        status: Status::Ok,
+                        answer: Some("abc,def".to_string()),
+                        verdict: None,
+                        error: None,
+                        timings: timings(&[1000, 1500, 1200]),
+                        allocations: None,
+                    },
+                ],
+            },
+            DayRun {
+                year: 2024,
+                day: 17,
+                combined: false,
+                parse: timings(&[1, 1, 1]),
+                parse_allocations: None,
+                parts: vec![PartRun {
+                    part: 1,
+                    status: Status::Ok,
+                    answer: Some("7".to_string()),
+                    verdict: None,
+                    error: None,
+                    timings: timings(&[2, 3, 4]),
+                    allocations: None,
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table() {
+        let runs: Vec<DayRun> = runs()
+            .into_iter()
+            .map(|mut run| {
+                run.parse = timings(&[run.parse.min().as_micros() as u64]);
+                for part in run.parts.iter_mut() {
+                    part.timings = timings(&[part.timings.min().as_micros() as u64]);
+                }
+                run
+            })
+            .collect();
+
+        assert_eq!(
+            table(&runs, 1),
+            indoc! {"
+                 Year | Day | Answer 1 | Answer 2 |   Parse |   Time 1 | Time 2 |  Total
+                ------+-----+----------+----------+---------+----------+--------+-------
+                 2024 |   1 | 42       | abc,def  | 10.00µs | 100.00µs | 1.00ms | 1.11ms
+                 2024 |  17 | 7        | -        |  1.00µs |   2.00µs |      - | 3.00µs
+                ------+-----+----------+----------+---------+----------+--------+-------
+                Total |     |          |          |         |          |        | 1.11ms
+            "}
+        );
+    }
+
+    #[test]
+    fn test_table_repeated() {
+        let table = table(&runs(), 3);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines[2],
+            " 2024 |   1 | 42       | abc,def  | 10.00µs / 20.00µs | 100.00µs / 200.00µs | 1.00ms / 1.20ms | 1.11ms / 1.42ms"
+        );
+        assert_eq!(
+            lines[5],
+            "Total |     |          |          |                   |                     |                 | 1.11ms / 1.42ms"
+        );
+    }
+
+    #[test]
+    fn test_combined() {
+        let mut runs = runs();
+        runs[0].combined = true;
+        for part in runs[0].parts.iter_mut() {
+            part.timings = Timings::default();
+        }
+        runs[0].parse_allocations = Some(Allocations::default());
+
+        let table = table(&runs, 1);
+        assert_eq!(
+            table.lines().nth(2),
+            Some(" 2024 |   1 | 42       | abc,def  |      - | combined | combined | 10.00µs")
+        );
+        assert!(csv(&runs).contains("\n2024,1,2,true,ok,\"abc,def\",,,,,,,10000,0,0,0,,,\n"));
+        assert!(allocations_table(&runs).contains("| both parts |"));
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut runs = runs();
+        runs[0].parts[0].verdict = Some(Verdict::Ok);
+        runs[0].parts[1].verdict = Some(Verdict::Wrong {
+            expected: "abc,deg".to_string(),
+        });
+        runs[1].parse = Timings::default();
+        runs[1].parts[0] = PartRun {
+            part: 1,
+            status: Status::MissingInput,
+            answer: None,
+            verdict: None,
+            error: Some("missing input".to_string()),
+            timings: Timings::default(),
+            allocations: None,
+        };
+
+        assert_eq!(
+            csv(&runs),
+            indoc! {r#"
+                year,day,part,combined,status,answer,verdict,expected,error,parse_ns,duration_ns,median_ns,total_ns,parse_count,parse_bytes,parse_peak,count,bytes,peak
+                2024,1,1,false,ok,42,ok,,,10000,100000,200000,1110000,,,,,,
+                2024,1,2,false,ok,"abc,def",wrong,"abc,deg",,10000,1000000,1200000,1110000,,,,,,
+                2024,17,1,false,missing_input,,,,missing input,,,,,,,,,,
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let runs = &runs()[1..];
+
+        assert_eq!(
+            json(runs),
+            indoc! {r#"
+                [
+                  {
+                    "year": 2024,
+                    "day": 17,
+                    "part": 1,
+                    "combined": false,
+                    "status": "ok",
+                    "answer": "7",
+                    "verdict": null,
+                    "expected": null,
+                    "error": null,
+                    "parse_ns": 1000,
+                    "duration_ns": 2000,
+                    "median_ns": 3000,
+                    "total_ns": 3000
+                  }
+                ]
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_allocations() {
+        let mut runs = runs();
+        assert_eq!(allocations_table(&runs), "");
+
+        runs[0].parse_allocations = Some(Allocations::default());
+        runs[0].parts[0].allocations = Some(Allocations {
+            count: 3,
+            bytes: 1536,
+            peak: 1024,
+        });
+        runs[0].parts[1].allocations = Some(Allocations {
+            count: 1,
+            bytes: 3 << 20,
+            peak: 3 << 20,
+        });
+
+        assert_eq!(
+            allocations_table(&runs),
+            indoc! {"
+
+                Year | Day | Step   | Allocations |   Bytes |    Peak
+                -----+-----+--------+-------------+---------+--------
+                2024 |   1 | parse  |           0 |      0B |      0B
+                2024 |   1 | part 1 |           3 | 1.50KiB | 1.00KiB
+                2024 |   1 | part 2 |           1 | 3.00MiB | 3.00MiB
+            "}
+        );
+        assert!(csv(&runs)
+            .contains("\n2024,1,1,false,ok,42,,,,10000,100000,200000,1110000,0,0,0,3,1536,1024\n"));
+        assert!(json(&runs).contains("\"parse_count\": 0,"));
+    }
+}
@@ -0,0 +1,420 @@
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Answers, Registration, Steps};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alloc::{Allocations, ALLOCATOR},
+    answers::Verdict,
+};
+
+/// Durations of the repeated runs of a step
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings(Vec<Duration>);
+
+impl Timings {
+    pub fn push(&mut self, duration: Duration) {
+        self.0.push(duration);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut durations = self.0.clone();
+        durations.sort();
+        durations
+            .get(durations.len().saturating_sub(1) / 2)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Outcome of running a part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    MissingInput,
+    Panic,
+    /// Exceeded the `--timeout`
+    Timeout,
+    /// Exceeded the `--memory-limit`
+    OutOfMemory,
+    /// The worker process died without reporting
+    Crash,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::MissingInput => "missing_input",
+            Status::Panic => "panic",
+            Status::Timeout => "timeout",
+            Status::OutOfMemory => "out_of_memory",
+            Status::Crash => "crash",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub status: Status,
+    /// `None` when the part could not be solved
+    pub answer: Option<String>,
+    /// `None` when the answer was not verified
+    #[serde(skip)]
+    pub verdict: Option<Verdict>,
+    /// Why the part could not be solved
+    pub error: Option<String>,
+    pub timings: Timings,
+    /// `None` when the allocations were not counted
+    pub allocations: Option<Allocations>,
+}
+
+impl PartRun {
+    fn new(part: u8) -> Self {
+        PartRun {
+            part,
+            status: Status::Ok,
+            answer: None,
+            verdict: None,
+            error: None,
+            timings: Timings::default(),
+            allocations: None,
+        }
+    }
+
+    pub fn fail(&mut self, status: Status, error: String) {
+        self.status = status;
+        self.answer = None;
+        self.error = Some(error);
+    }
+}
+
+/// Answers and timings of a day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    /// Whether both parts are solved while parsing, `parse` then timing the whole day and the
+    /// parts not being timed
+    pub combined: bool,
+    pub parse: Timings,
+    /// `None` when the allocations were not counted
+    pub parse_allocations: Option<Allocations>,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    /// Run of the `parts` that `day` has
+    pub fn new(day: &Registration, parts: &[u8]) -> Self {
+        DayRun {
+            year: day.year,
+            day: day.day,
+            combined: day.steps == Steps::Combined,
+            parse: Timings::default(),
+            parse_allocations: None,
+            parts: parts
+                .iter()
+                .filter(|&&part| part <= day.parts)
+                .map(|&part| PartRun::new(part))
+                .collect(),
+        }
+    }
+
+    /// Run of a day whose input could not be read
+    pub fn missing_input(day: &Registration, parts: &[u8], error: String) -> Self {
+        let mut run = DayRun::new(day, parts);
+        for part in run.parts.iter_mut() {
+            part.fail(Status::MissingInput, error.clone());
+        }
+        run
+    }
+
+    /// Whether every part ran and none of the answers is wrong
+    pub fn is_ok(&self) -> bool {
+        self.parts.iter().all(|run| {
+            run.status == Status::Ok && !matches!(run.verdict, Some(Verdict::Wrong { .. }))
+        })
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PartRun> {
+        self.parts.iter().find(|run| run.part == part)
+    }
+
+    /// Sum of the steps durations, using `stat` to pick a duration for each step
+    pub fn total(&self, stat: fn(&Timings) -> Duration) -> Duration {
+        stat(&self.parse)
+            + self
+                .parts
+                .iter()
+                .map(|run| stat(&run.timings))
+                .sum::<Duration>()
+    }
+}
+
+/// Answer of `part` and the time taken to solve it, the answer being formatted once timed
+fn solve(parsed: &dyn Answers, part: u8) -> (String, Duration) {
+    match part {
+        1 => parsed.part1(),
+        2 => parsed.part2(),
+        _ => unreachable!("there are only 2 parts"),
+    }
+}
+
+thread_local! {
+    /// Whether the panics of this thread are caught by [`catch_panic`]
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+
+    /// Location of the last panic caught on this thread
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keep the location of the panics caught while solving for the report, instead of printing them.
+///
+/// Any other panic is printed by the previous hook, including the panics of the rayon threads
+/// that rayon resumes on the thread solving the day: with `--jobs` they could belong to any of
+/// the days being solved, so their location is printed rather than reported for one of them.
+pub fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CAPTURING.get() {
+            let location = info.location().map(|location| location.to_string());
+            PANIC_LOCATION.set(location);
+        } else {
+            previous(info);
+        }
+    }));
+}
+
+/// Run `f`, turning a panic into its message and location
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let capturing = CAPTURING.replace(true);
+    PANIC_LOCATION.take();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.set(capturing);
+
+    result.map_err(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown panic".to_string(),
+            },
+        };
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("{} at {}", message, location),
+            None => message,
+        }
+    })
+}
+
+/// Parse `input` and solve `parts` of `day`, `repeat` times.
+///
+/// A panic fails the parts it prevents from being solved, without stopping the run. When the
+/// allocator counts, each step runs once more to count its allocations, without boxing the parsed
+/// input nor formatting the answers. The parts of a combined day only return the answers found
+/// while parsing, so they are neither timed nor counted.
+pub fn run_day(day: &Registration, input: &str, parts: &[u8], repeat: usize) -> DayRun {
+    let mut run = DayRun::new(day, parts);
+
+    for _ in 0..repeat.max(1) {
+        let start = Instant::now();
+        let parsed = match catch_panic(|| (day.parse)(input)) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!("{} day {} parse panicked: {}", day.year, day.day, error);
+                for part_run in run.parts.iter_mut() {
+                    part_run.fail(Status::Panic, error.clone());
+                }
+                break;
+            }
+        };
+        run.parse.push(start.elapsed());
+        if run.parse_allocations.is_none() && ALLOCATOR.is_enabled() {
+            run.parse_allocations = Some(ALLOCATOR.measure(|| (day.parse_only)(input)));
+        }
+
+        for part_run in run.parts.iter_mut() {
+            if part_run.status != Status::Ok {
+                continue;
+            }
+
+            match catch_panic(|| solve(parsed.as_ref(), part_run.part)) {
+                Ok((answer, elapsed)) => {
+                    part_run.answer = Some(answer);
+                    if run.combined {
+                        continue;
+                    }
+                    part_run.timings.push(elapsed);
+                    if part_run.allocations.is_none() && ALLOCATOR.is_enabled() {
+                        part_run.allocations = Some(ALLOCATOR.measure(|| match part_run.part {
+                            1 => parsed.run_part1(),
+                            _ => parsed.run_part2(),
+                        }));
+                    }
+                }
+                Err(error) => {
+                    eprintln!(
+                        "{} day {} part {} panicked: {}",
+                        day.year, day.day, part_run.part, error
+                    );
+                    part_run.fail(Status::Panic, error);
+                }
+            }
+        }
+    }
+
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(millis: &[u64]) -> Timings {
+        Timings(millis.iter().map(|&ms| Duration::from_millis(ms)).collect())
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = timings(&[5, 1, 3, 2, 8]);
+
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_millis(3));
+        assert_eq!(Timings::default().median(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_run_day() {
+        let day = rust_2025::days()[0];
+        let run = run_day(day, "R50\nL5\n", &[2], 3);
+
+        assert_eq!((run.year, run.day), (2025, 1));
+        assert_eq!(run.parse.0.len(), 3);
+        assert!(run.part(1).is_none());
+        assert_eq!(run.part(2).unwrap().answer.as_deref(), Some("1"));
+        assert_eq!(run.part(2).unwrap().timings.0.len(), 3);
+    }
+
+    #[test]
+    fn test_run_day_combined() {
+        let day = rust_2023::days()[0];
+        let run = run_day(day, "1abc2\ntreb7uchet\n", &[1, 2], 2);
+
+        assert!(run.combined);
+        assert_eq!(run.parse.0.len(), 2);
+        for part in &run.parts {
+            assert_eq!(part.answer.as_deref(), Some("89"));
+            assert!(part.timings.is_empty());
+        }
+        assert_eq!(run.total(Timings::min), run.parse.min());
+    }
+
+    #[test]
+    fn test_run_day_single_part() {
+        let day = rust_2024::days()[24];
+        let run = run_day(day, "#####\n.....\n", &[1, 2], 1);
+
+        assert_eq!(day.day, 25);
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, 1);
+        assert!(run.is_ok());
+    }
+
+    #[test]
+    fn test_run_day_panic() {
+        let day = rust_2025::days()[0];
+        let run = run_day(day, "X1\n", &[1, 2], 2);
+
+        for part in &run.parts {
+            assert_eq!(part.status, Status::Panic);
+            assert_eq!(part.answer, None);
+            // the location is only known once the hook is installed by another test
+            assert!(part.error.as_deref().unwrap().starts_with("never happens"));
+        }
+        assert!(!run.is_ok());
+    }
+
+    #[test]
+    fn test_panic_hook() {
+        thread_local! {
+            static PRINTED: Cell<bool> = const { Cell::new(false) };
+        }
+        panic::set_hook(Box::new(|_| PRINTED.set(true)));
+        install_panic_hook();
+
+        let error = catch_panic(|| panic!("in the day")).unwrap_err();
+        assert!(
+            error.starts_with("in the day at aoc/src/run.rs:"),
+            "{}",
+            error
+        );
+        assert!(!PRINTED.get());
+
+        let _ = panic::catch_unwind(|| panic!("outside of the days"));
+        assert!(PRINTED.get());
+    }
+
+    #[test]
+    fn test_panic_on_rayon_thread() {
+        install_panic_hook();
+
+        // rayon resumes the panic of its thread on the calling one, without its location
+        let error = catch_panic(|| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap()
+                .install(|| rayon::join(|| {}, || panic!("on a rayon thread")))
+        })
+        .unwrap_err();
+        assert_eq!(error, "on a rayon thread");
+    }
+
+    #[test]
+    fn test_panic_locations_per_thread() {
+        install_panic_hook();
+
+        let errors: Vec<String> = std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..4)
+                .map(|i| scope.spawn(move || catch_panic(|| panic!("day {}", i)).unwrap_err()))
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| thread.join().unwrap())
+                .collect()
+        });
+        for (i, error) in errors.iter().enumerate() {
+            assert!(
+                error.starts_with(&format!("day {} at aoc/src/run.rs:", i)),
+                "{}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_day_parse_panic() {
+        let day = rust_2023::days()[0];
+        let run = run_day(day, "no digits\n", &[1, 2], 1);
+
+        assert!(run.parse.is_empty());
+        assert!(run
+            .parts
+            .iter()
+            .all(|part| part.status == Status::Panic && part.error.is_some()));
+    }
+}
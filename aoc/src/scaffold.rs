@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+
+const TEMPLATE: &str = include_str!("../../template/rust/mod.rs");
+const TS_TEMPLATE: &str = include_str!("../../template/ts/index.ts");
+
+/// Language of a new day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    /// A module of the year crate, from `template/rust`
+    Rust,
+    /// A script run with ts-node, from `template/ts`
+    Ts,
+}
+
+/// Closest directory containing `dir` whose `Cargo.toml` declares a workspace
+fn find_workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Root of the workspace the current directory is in, containing a directory for each year
+pub fn workspace_root() -> Result<PathBuf, String> {
+    let current_dir = std::env::current_dir()
+        .map_err(|error| format!("cannot get the current directory: {}", error))?;
+    find_workspace_root(&current_dir).ok_or_else(|| {
+        format!(
+            "{} is not in the Advent of Code workspace",
+            current_dir.display()
+        )
+    })
+}
+
+pub fn module_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join("src")
+        .join(format!("day{}", day))
+}
+
+/// Add `pub mod day<day>;` to `lib`, keeping the days sorted, unless it is already declared
+fn declare_module(lib: &str, day: u8) -> Option<String> {
+    let day_of = |line: &str| -> Option<u8> {
+        line.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    };
+    if lib.lines().any(|line| day_of(line) == Some(day)) {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let declaration = format!("pub mod day{};", day);
+    let position = match lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|d| d > day))
+    {
+        Some(position) => position,
+        None => lines
+            .iter()
+            .rposition(|line| day_of(line).is_some())
+            .map_or(0, |last| last + 1),
+    };
+    lines.insert(position, &declaration);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Create the directory of `day` of `year` from the template of `lang`, then declare a Rust
+/// module in the year crate.
+///
+/// Existing files are left untouched so it can be run again safely, returns what was done.
+pub fn new_day(root: &Path, year: u16, day: u8, lang: Lang) -> Result<Vec<String>, String> {
+    let year_dir = root.join(year.to_string());
+    if lang == Lang::Rust && !year_dir.join("Cargo.toml").exists() {
+        return Err(format!("there is no Rust crate for {}", year));
+    }
+
+    let mut actions = vec![];
+    let module_dir = module_dir(root, year, day);
+    fs::create_dir_all(&module_dir)
+        .map_err(|error| format!("cannot create {}: {}", module_dir.display(), error))?;
+
+    // the input is created empty so that the tests including it compile
+    let template = match lang {
+        Lang::Rust => ("mod.rs", TEMPLATE),
+        Lang::Ts => ("index.ts", TS_TEMPLATE),
+    };
+    for (name, content) in [template, ("input.txt", "")] {
+        let path = module_dir.join(name);
+        if path.exists() {
+            continue;
+        }
+        fs::write(&path, content)
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+        actions.push(format!("created {}", path.display()));
+    }
+    if lang == Lang::Ts {
+        return Ok(actions);
+    }
+
+    let lib_path = year_dir.join("src").join("lib.rs");
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|error| format!("cannot read {}: {}", lib_path.display(), error))?;
+    if let Some(lib) = declare_module(&lib, day) {
+        fs::write(&lib_path, lib)
+            .map_err(|error| format!("cannot write {}: {}", lib_path.display(), error))?;
+        actions.push(format!(
+            "declared `pub mod day{};` in {}",
+            day,
+            lib_path.display()
+        ));
+    }
+
+    Ok(actions)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_declare_module() {
+        let lib = indoc! {"
+            pub mod day1;
+            pub mod day2;
+            pub mod day10;
+
+            pub fn days() {}
+        "};
+
+        assert_eq!(
+            declare_module(lib, 3).unwrap(),
+            indoc! {"
+                pub mod day1;
+                pub mod day2;
+                pub mod day3;
+                pub mod day10;
+
+                pub fn days() {}
+            "}
+        );
+        assert_eq!(
+            declare_module(lib, 11).unwrap(),
+            indoc! {"
+                pub mod day1;
+                pub mod day2;
+                pub mod day10;
+                pub mod day11;
+
+                pub fn days() {}
+            "}
+        );
+        assert_eq!(declare_module(lib, 2), None);
+    }
+
+    #[test]
+    fn test_workspace_root() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+        assert_eq!(workspace_root(), Ok(root.to_path_buf()));
+        assert_eq!(
+            find_workspace_root(&root.join("2024/src/day1")),
+            Some(root.to_path_buf())
+        );
+        assert_eq!(find_workspace_root(Path::new("/")), None);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        fs::create_dir_all(root.join("2025/src")).unwrap();
+        fs::write(root.join("2025/Cargo.toml"), "").unwrap();
+        fs::write(root.join("2025/src/lib.rs"), "pub mod day1;\n").unwrap();
+
+        assert_eq!(new_day(&root, 2025, 2, Lang::Rust).unwrap().len(), 3);
+        assert_eq!(
+            fs::read_to_string(root.join("2025/src/lib.rs")).unwrap(),
+            "pub mod day1;\npub mod day2;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2025/src/day2/mod.rs")).unwrap(),
+            TEMPLATE
+        );
+        assert_eq!(new_day(&root, 2025, 2, Lang::Rust), Ok(vec![]));
+        assert!(new_day(&root, 2020, 1, Lang::Rust).is_err());
+
+        // a TypeScript day needs no crate
+        assert_eq!(new_day(&root, 2020, 1, Lang::Ts).unwrap().len(), 2);
+        assert_eq!(
+            fs::read_to_string(root.join("2020/src/day1/index.ts")).unwrap(),
+            TS_TEMPLATE
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
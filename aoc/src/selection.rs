@@ -0,0 +1,111 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// Comma separated numbers and inclusive ranges, e.g. `1-10,17`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranges(Vec<RangeInclusive<u16>>);
+
+impl Ranges {
+    pub fn contains(&self, n: u16) -> bool {
+        self.0.iter().any(|range| range.contains(&n))
+    }
+}
+
+fn parse_number(s: &str) -> Result<u16, String> {
+    s.trim()
+        .parse()
+        .map_err(|_| format!("invalid number `{}`", s))
+}
+
+impl FromStr for Ranges {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| {
+                let (start, end) = item.split_once('-').unwrap_or((item, item));
+                let (start, end) = (parse_number(start)?, parse_number(end)?);
+                if end < start {
+                    return Err(format!("empty range `{}`", item));
+                }
+                Ok(start..=end)
+            })
+            .collect::<Result<_, _>>()
+            .map(Ranges)
+    }
+}
+
+/// Days of some years, e.g. `2024:1-10,17`, or every day of the years with `2023-2024`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    pub years: Ranges,
+    pub days: Option<Ranges>,
+}
+
+impl Selector {
+    pub fn matches(&self, year: u16, day: u8) -> bool {
+        self.years.contains(year)
+            && self
+                .days
+                .as_ref()
+                .is_none_or(|days| days.contains(day.into()))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((years, days)) => Ok(Selector {
+                years: years.parse()?,
+                days: Some(days.parse()?),
+            }),
+            None => Ok(Selector {
+                years: s.parse()?,
+                days: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges() {
+        let ranges: Ranges = "1-10,17".parse().unwrap();
+
+        assert!(ranges.contains(1));
+        assert!(ranges.contains(10));
+        assert!(!ranges.contains(11));
+        assert!(ranges.contains(17));
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        assert!("".parse::<Ranges>().is_err());
+        assert!("1-".parse::<Ranges>().is_err());
+        assert!("10-1".parse::<Ranges>().is_err());
+        assert!("one".parse::<Ranges>().is_err());
+    }
+
+    #[test]
+    fn test_selector() {
+        let selector: Selector = "2024:1-10,17".parse().unwrap();
+
+        assert!(selector.matches(2024, 3));
+        assert!(selector.matches(2024, 17));
+        assert!(!selector.matches(2024, 12));
+        assert!(!selector.matches(2023, 3));
+    }
+
+    #[test]
+    fn test_selector_whole_year() {
+        let selector: Selector = "2023-2024".parse().unwrap();
+
+        assert!(selector.matches(2023, 25));
+        assert!(selector.matches(2024, 1));
+        assert!(!selector.matches(2025, 1));
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{fetch::USER_AGENT, html};
+
+/// What the website answered to a guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked, another one was submitted too recently
+    RateLimited,
+}
+
+impl Response {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Response::Correct => "correct",
+            Response::TooHigh => "too high",
+            Response::TooLow => "too low",
+            Response::Wrong => "wrong",
+            Response::RateLimited => "rate limited",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub response: Response,
+    /// Text of the reply, e.g. how long to wait when rate limited
+    pub message: String,
+}
+
+/// Something checking answers, the Advent of Code website or a fake one in tests
+pub trait AnswerClient {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Reply, String>;
+}
+
+/// Submits the answers of the user logged in with `session` on the Advent of Code website
+pub struct HttpClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        HttpClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+impl AnswerClient for HttpClient {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Reply, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| format!("cannot submit to {}: {}", url, error))?
+            .into_string()
+            .map_err(|error| format!("cannot submit to {}: {}", url, error))?;
+
+        parse_reply(&html)
+    }
+}
+
+/// Reply of the website from the text of its `<article>`
+fn parse_reply(html: &str) -> Result<Reply, String> {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let message = html::text(article);
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let response = if message.contains("That's the right answer") {
+        Response::Correct
+    } else if message.contains("You gave an answer too recently") {
+        Response::RateLimited
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Response::TooHigh
+        } else if message.contains("too low") {
+            Response::TooLow
+        } else {
+            Response::Wrong
+        }
+    } else {
+        return Err(format!("unexpected reply: {}", message));
+    };
+
+    Ok(Reply { response, message })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+}
+
+/// Every answer submitted by a user, stored as one JSON guess per line
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Guesses stored at `path`, a missing file meaning none
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("cannot read {}: {}", path.display(), error)),
+        };
+        let guesses = content
+            .lines()
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|error| format!("invalid guess in {}: {}", path.display(), error))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History {
+            path: path.to_path_buf(),
+            guesses,
+        })
+    }
+
+    fn guesses(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| (guess.year, guess.day, guess.part) == (year, day, part))
+    }
+
+    /// Guesses the website answered as correct
+    pub fn correct(&self) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(|guess| guess.response == Response::Correct)
+    }
+
+    /// Why `answer` is known to be wrong without submitting it, if it is
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        if answer.trim().is_empty() {
+            return Err("not submitting an empty answer".to_string());
+        }
+        let number = answer.parse::<i64>().ok();
+
+        for guess in self.guesses(year, day, part) {
+            let guessed = guess.answer.parse::<i64>().ok();
+            let refusal = match (guess.response, number, guessed) {
+                (Response::RateLimited, _, _) => None,
+                (Response::Correct, _, _) if guess.answer == answer => {
+                    Some("it is already known to be correct".to_string())
+                }
+                (Response::Correct, _, _) => {
+                    Some(format!("the correct answer is {}", guess.answer))
+                }
+                _ if guess.answer == answer => Some(format!(
+                    "it was already submitted and was {}",
+                    guess.response.as_str()
+                )),
+                (Response::TooHigh, Some(number), Some(guessed)) if number >= guessed => {
+                    Some(format!("{} was already too high", guess.answer))
+                }
+                (Response::TooLow, Some(number), Some(guessed)) if number <= guessed => {
+                    Some(format!("{} was already too low", guess.answer))
+                }
+                _ => None,
+            };
+            if let Some(refusal) = refusal {
+                return Err(format!("not submitting {}, {}", answer, refusal));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<(), String> {
+        let line = serde_json::to_string(&guess).unwrap();
+        let write = || {
+            if let Some(dir) = self.path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{}", line)
+        };
+        write().map_err(|error| format!("cannot write {}: {}", self.path.display(), error))?;
+
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+/// Submit `answer` unless `history` knows it is wrong, recording the reply
+pub fn submit(
+    client: &dyn AnswerClient,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, String> {
+    history.check(year, day, part, answer)?;
+
+    let reply = client.submit(year, day, part, answer)?;
+    history.record(Guess {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        response: reply.response,
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    })?;
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// Checks answers against `correct`, counting the submissions
+    struct FakeClient {
+        correct: i64,
+        submissions: Cell<usize>,
+    }
+
+    impl AnswerClient for FakeClient {
+        fn submit(&self, _year: u16, _day: u8, _part: u8, answer: &str) -> Result<Reply, String> {
+            self.submissions.set(self.submissions.get() + 1);
+            let response = match answer.parse::<i64>() {
+                Ok(answer) if answer > self.correct => Response::TooHigh,
+                Ok(answer) if answer < self.correct => Response::TooLow,
+                Ok(_) => Response::Correct,
+                Err(_) => Response::Wrong,
+            };
+            Ok(Reply {
+                response,
+                message: String::new(),
+            })
+        }
+    }
+
+    fn history(name: &str) -> History {
+        let path =
+            std::env::temp_dir().join(format!("aoc-guesses-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        History::load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_submit() {
+        let client = FakeClient {
+            correct: 42,
+            submissions: Cell::new(0),
+        };
+        let mut history = history("submit");
+        let mut submit =
+            |answer| submit(&client, &mut history, 2024, 1, 1, answer).map(|reply| reply.response);
+
+        assert_eq!(submit("50"), Ok(Response::TooHigh));
+        assert!(submit("60").is_err());
+        assert!(submit("50").is_err());
+        assert_eq!(submit("10"), Ok(Response::TooLow));
+        assert!(submit("5").is_err());
+        assert_eq!(submit("abc"), Ok(Response::Wrong));
+        assert!(submit("abc").is_err());
+        assert_eq!(submit("42"), Ok(Response::Correct));
+        assert!(submit("42").is_err());
+        assert!(submit("43").is_err());
+        assert_eq!(
+            submit(" \n"),
+            Err("not submitting an empty answer".to_string())
+        );
+        assert!(submit("").is_err());
+        assert_eq!(client.submissions.get(), 4);
+
+        let reloaded = History::load(&history.path).unwrap();
+        assert_eq!(reloaded.guesses, history.guesses);
+        assert_eq!(reloaded.guesses.len(), 4);
+        assert!(reloaded.check(2024, 1, 2, "50").is_ok());
+        fs::remove_file(&history.path).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limited_guess_can_be_resubmitted() {
+        let mut history = history("rate-limited");
+        history
+            .record(Guess {
+                year: 2024,
+                day: 1,
+                part: 1,
+                answer: "50".to_string(),
+                response: Response::RateLimited,
+                time: 0,
+            })
+            .unwrap();
+
+        assert!(history.check(2024, 1, 1, "50").is_ok());
+        fs::remove_file(&history.path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let reply = |article: &str| {
+            parse_reply(&format!(
+                "<main>\n<article><p>{}</p></article>\n</main>",
+                article
+            ))
+            .map(|reply| reply.response)
+        };
+
+        assert_eq!(
+            reply("That's the right answer! You are <em>one gold star</em> closer."),
+            Ok(Response::Correct)
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too high. Please wait one minute."),
+            Ok(Response::TooHigh)
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too low."),
+            Ok(Response::TooLow)
+        );
+        assert_eq!(
+            reply("That's not the right answer. If you're stuck, make sure you're using the full input data"),
+            Ok(Response::Wrong)
+        );
+        assert_eq!(
+            reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait."),
+            Ok(Response::RateLimited)
+        );
+        assert!(reply("You don't seem to be solving the right level.").is_err());
+    }
+}
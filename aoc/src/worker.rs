@@ -0,0 +1,326 @@
+use std::{
+    io::{self, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Registration;
+
+use crate::{
+    run::{self, DayRun, Status},
+    Args,
+};
+
+/// Resources a part may use, enforced by running it in a worker process
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock duration of the worker, parsing and repetitions included
+    pub timeout: Option<Duration>,
+    /// Address space of the worker, in MiB
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.memory.is_some()
+    }
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Printed by the standard library when an allocation fails
+const ALLOCATION_FAILED: &str = "memory allocation of";
+
+/// Run each of `parts` of `day` in its own worker process, killed if it exceeds the limits.
+///
+/// The parts of a combined day are solved together while parsing, so they share a worker.
+pub fn run_day(day: &Registration, input: &str, parts: &[u8], args: &Args) -> DayRun {
+    run_day_with(day, input, parts, args.limits(), |parts| {
+        worker_command(day, parts, args)
+    })
+}
+
+/// Run each of `parts` of `day` in the worker process built by `command` for the parts it solves,
+/// killed if it exceeds `limits`
+pub fn run_day_with(
+    day: &Registration,
+    input: &str,
+    parts: &[u8],
+    limits: Limits,
+    command: impl Fn(&[u8]) -> Result<Command, (Status, String)>,
+) -> DayRun {
+    let mut run = DayRun::new(day, parts);
+    let parts: Vec<u8> = run.parts.iter().map(|part_run| part_run.part).collect();
+    let groups: Vec<&[u8]> = if run.combined {
+        vec![&parts]
+    } else {
+        parts.chunks(1).collect()
+    };
+
+    for group in groups.into_iter().filter(|group| !group.is_empty()) {
+        let result = command(group)
+            .and_then(|command| run_command(command, input, limits))
+            .and_then(|output| parse_output(&output));
+
+        match result {
+            Ok(worker_run) => {
+                if run.parse.is_empty() {
+                    run.parse = worker_run.parse;
+                    run.parse_allocations = worker_run.parse_allocations;
+                }
+                for worker_part in worker_run.parts {
+                    if let Some(part_run) = run
+                        .parts
+                        .iter_mut()
+                        .find(|part_run| part_run.part == worker_part.part)
+                    {
+                        *part_run = worker_part;
+                    }
+                }
+            }
+            Err((status, error)) => {
+                for part_run in run
+                    .parts
+                    .iter_mut()
+                    .filter(|part_run| group.contains(&part_run.part))
+                {
+                    eprintln!(
+                        "{} day {} part {}: {}",
+                        day.year, day.day, part_run.part, error
+                    );
+                    part_run.fail(status, error.clone());
+                }
+            }
+        }
+    }
+
+    run
+}
+
+/// Run printed by a worker, on the last line starting a JSON object as a test harness serving as
+/// the worker prints its own lines around it
+fn parse_output(output: &str) -> Result<DayRun, (Status, String)> {
+    let line = output
+        .lines()
+        .rfind(|line| line.starts_with('{'))
+        .unwrap_or(output);
+    serde_json::from_str(line)
+        .map_err(|error| (Status::Crash, format!("invalid worker output: {}", error)))
+}
+
+/// Command running `parts` of `day` in a worker process of this executable
+fn worker_command(
+    day: &Registration,
+    parts: &[u8],
+    args: &Args,
+) -> Result<Command, (Status, String)> {
+    let exe = std::env::current_exe().map_err(|error| {
+        (
+            Status::Crash,
+            format!("cannot find the worker executable: {}", error),
+        )
+    })?;
+
+    let mut command = Command::new(exe);
+    command.arg(format!("{}:{}", day.year, day.day));
+    if let [part] = parts {
+        command.args(["--part", &part.to_string()]);
+    }
+    command
+        .args(["--repeat", &args.repeat.to_string()])
+        .args(["--input", "-", "--worker"]);
+    if let Some(memory) = args.memory_limit {
+        command.args(["--memory-limit", &memory.to_string()]);
+    }
+    if args.allocations {
+        command.arg("--allocations");
+    }
+    if args.single_threaded {
+        command.arg("--single-threaded");
+    }
+
+    Ok(command)
+}
+
+/// Run `command` with `input` as its standard input, returning its standard output.
+///
+/// The standard error of the command is forwarded once it exits.
+fn run_command(
+    mut command: Command,
+    input: &str,
+    limits: Limits,
+) -> Result<String, (Status, String)> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| (Status::Crash, format!("cannot start worker: {}", error)))?;
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    let mut stderr = child.stderr.take().unwrap();
+
+    let start = Instant::now();
+    let (exit_status, stdout, stderr) = thread::scope(|scope| {
+        // the worker may exit before reading its whole input, a broken pipe is expected then
+        scope.spawn(move || stdin.write_all(input.as_bytes()));
+        let stdout = scope.spawn(move || read_to_string(&mut stdout));
+        let stderr = scope.spawn(move || read_to_string(&mut stderr));
+
+        let exit_status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break Some(status),
+                Ok(None)
+                    if limits
+                        .timeout
+                        .is_some_and(|timeout| start.elapsed() >= timeout) =>
+                {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    break None;
+                }
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(error) => panic!("cannot wait for worker: {}", error),
+            }
+        };
+
+        (exit_status, stdout.join().unwrap(), stderr.join().unwrap())
+    });
+    eprint!("{}", stderr);
+
+    let Some(exit_status) = exit_status else {
+        return Err((
+            Status::Timeout,
+            format!("exceeded the timeout of {:?}", limits.timeout.unwrap()),
+        ));
+    };
+    if exit_status.success() {
+        Ok(stdout)
+    } else if let (Some(memory), true) = (limits.memory, stderr.contains(ALLOCATION_FAILED)) {
+        Err((
+            Status::OutOfMemory,
+            format!("exceeded the memory limit of {} MiB", memory),
+        ))
+    } else {
+        Err((Status::Crash, format!("worker failed with {}", exit_status)))
+    }
+}
+
+fn read_to_string(reader: &mut impl Read) -> String {
+    let mut output = String::new();
+    let _ = reader.read_to_string(&mut output);
+    output
+}
+
+/// Run `parts` of `day` and print the run as JSON, for the process that spawned this worker.
+///
+/// The run is written to the standard output directly, as the test harness captures `println!`
+/// in the tests serving as workers.
+pub fn serve(day: &Registration, input: &str, parts: &[u8], repeat: u16) {
+    let run = run::run_day(day, input, parts, repeat.into());
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{}", serde_json::to_string(&run).unwrap());
+}
+
+/// Limit the address space of the current process to `memory` MiB
+#[cfg(unix)]
+pub fn limit_memory(memory: u64) -> io::Result<()> {
+    let bytes = memory.saturating_mul(1024 * 1024) as libc::rlim_t;
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+
+    // SAFETY: `limit` is a valid rlimit that outlives the call
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn limit_memory(_memory: u64) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "memory limits are only supported on unix",
+    ))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", script]);
+        command
+    }
+
+    #[test]
+    fn test_run_command() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: None,
+        };
+
+        assert_eq!(
+            run_command(shell("cat"), "1\n2\n", limits),
+            Ok("1\n2\n".to_string())
+        );
+        assert_eq!(
+            run_command(shell("exit 3"), "", limits),
+            Err((
+                Status::Crash,
+                "worker failed with exit status: 3".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_run_command_timeout() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            memory: None,
+        };
+        let start = Instant::now();
+
+        assert_eq!(
+            run_command(shell("exec sleep 10"), "", limits),
+            Err((Status::Timeout, "exceeded the timeout of 50ms".to_string()))
+        );
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    /// Worker of `test_run_command_out_of_memory`, run in a child process
+    #[test]
+    #[ignore = "only run as a worker, as it aborts"]
+    fn allocate_past_memory_limit() {
+        if std::env::var_os("AOC_TEST_WORKER").is_none() {
+            return;
+        }
+        limit_memory(64).unwrap();
+        std::hint::black_box(vec![1u8; 256 << 20]);
+    }
+
+    #[test]
+    fn test_run_command_out_of_memory() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(60)),
+            memory: Some(64),
+        };
+        let mut worker = Command::new(std::env::current_exe().unwrap());
+        worker
+            .args(["worker::tests::allocate_past_memory_limit", "--exact"])
+            .args(["--ignored", "--test-threads", "1"])
+            .env("AOC_TEST_WORKER", "1");
+
+        assert_eq!(
+            run_command(worker, "", limits),
+            Err((
+                Status::OutOfMemory,
+                "exceeded the memory limit of 64 MiB".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::hint::black_box;
+
+use aoc_common::{Registration, Steps};
+#[doc(hidden)]
+pub use criterion;
+use criterion::Criterion;
+
+/// Benchmark the parsing, part1 and part2 of every day in `days`, in a group per day.
+///
+/// The parts are benchmarked on the already parsed input, without formatting their answers. A
+/// day parsing its input as is has no parse benchmark, and a combined day only has a `solve`
+/// benchmark of its parsing, as it solves both parts. Days whose `input.txt` is missing or empty
+/// are skipped, as the inputs are not committed.
+pub fn bench_days(c: &mut Criterion, days: &[&Registration]) {
+    for day in days {
+        let input = match std::fs::read_to_string(day.input_path()) {
+            Ok(input) if !input.is_empty() => input,
+            _ => {
+                eprintln!(
+                    "Skipping {} day {}, its input is missing",
+                    day.year, day.day
+                );
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("day{}", day.day));
+        match day.steps {
+            Steps::Combined => {
+                group.bench_function(format!("day{}_solve", day.day), |b| {
+                    b.iter(|| (day.parse_only)(black_box(&input)))
+                });
+            }
+            steps => {
+                if steps == Steps::Separate {
+                    group.bench_function(format!("day{}_parse", day.day), |b| {
+                        b.iter(|| (day.parse_only)(black_box(&input)))
+                    });
+                }
+                let parsed = (day.parse)(&input);
+                group.bench_function(format!("day{}_part1", day.day), |b| {
+                    b.iter(|| parsed.run_part1())
+                });
+                if day.parts == 2 {
+                    group.bench_function(format!("day{}_part2", day.day), |b| {
+                        b.iter(|| parsed.run_part2())
+                    });
+                }
+            }
+        }
+        group.finish();
+    }
+}
+
+/// Define the `main` of a benchmark target running [`bench_days`] on `days`, e.g.
+/// `aoc_bench::main!(rust_2024::days())` in `benches/bench_days.rs` with `harness = false`.
+#[macro_export]
+macro_rules! main {
+    ($days:expr) => {
+        fn bench_days(c: &mut $crate::criterion::Criterion) {
+            $crate::bench_days(c, &$days);
+        }
+
+        $crate::criterion::criterion_group!(benches, bench_days);
+        $crate::criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,141 @@
+use std::ops::RangeInclusive;
+
+/// Small deterministic random number generator (SplitMix64), so a failing input can be
+/// generated again from its seed
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `range`, up to a negligible bias
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with a probability of `numerator / denominator`
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+}
+
+/// Slow but obviously correct solver of a part, returning its answer
+pub type Reference = fn(&str) -> String;
+
+/// Random inputs of a day with slow but obviously correct answers, to cross-check its solution
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// A valid random input, larger as `size` grows
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// Answer of each part computed by a reference solver, if the part has one
+    pub reference: [Option<Reference>; 2],
+}
+
+inventory::collect!(Generator);
+
+/// All the registered generators of `year`, sorted by day
+pub fn generators(year: u16) -> Vec<&'static Generator> {
+    let mut generators: Vec<_> = inventory::iter::<Generator>
+        .into_iter()
+        .filter(|generator| generator.year == year)
+        .collect();
+    generators.sort_by_key(|generator| generator.day);
+    generators
+}
+
+/// Register a [`Generator`] of the calling day module, returned by [`generators`].
+///
+/// - `generator!(generate, reference_part1)` only checks part 1.
+/// - `generator!(generate, reference_part1, reference_part2)` checks both parts.
+///
+/// `generate` is a `fn(&mut Rng, usize) -> String` and the reference solvers take the input
+/// and return anything that displays like the answer of the solution. The days keep the call, the
+/// generator and the reference solvers in a `check` module behind
+/// `#[cfg(any(test, feature = "check"))]`.
+#[macro_export]
+macro_rules! generator {
+    (@submit $generate:path, $reference:expr) => {
+        $crate::inventory::submit! {
+            $crate::Generator {
+                year: $crate::trailing_number(env!("CARGO_PKG_NAME")),
+                day: $crate::day_number(module_path!()),
+                generate: $generate,
+                reference: $reference,
+            }
+        }
+    };
+    ($generate:path, $part1:path) => {
+        $crate::generator!(@submit $generate, [
+            Some(|input: &str| $part1(input).to_string()),
+            None,
+        ]);
+    };
+    ($generate:path, $part1:path, $part2:path) => {
+        $crate::generator!(@submit $generate, [
+            Some(|input: &str| $part1(input).to_string()),
+            Some(|input: &str| $part2(input).to_string()),
+        ]);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod day3 {
+        mod check {
+            use crate::Rng;
+
+            fn generate(rng: &mut Rng, size: usize) -> String {
+                (0..size)
+                    .map(|_| rng.range(0..=9).to_string() + "\n")
+                    .collect()
+            }
+
+            fn reference(input: &str) -> usize {
+                input.lines().count()
+            }
+
+            generator!(generate, reference);
+        }
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let numbers: Vec<u64> = (0..1000).map(|_| rng.range(3..=5)).collect();
+
+        assert!(numbers.iter().all(|n| (3..=5).contains(n)));
+        assert!((3..=5).all(|n| numbers.contains(&n)));
+        assert_eq!(Rng::new(7).range(3..=5), numbers[0]);
+        assert_eq!(Rng::new(1).range(0..=u64::MAX), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn test_generator() {
+        let generator = generators(0)
+            .into_iter()
+            .find(|generator| generator.day == 3)
+            .unwrap();
+        let input = (generator.generate)(&mut Rng::new(0), 4);
+
+        assert_eq!(input.lines().count(), 4);
+        assert_eq!(generator.reference[0].unwrap()(&input), "4");
+        assert!(generator.reference[1].is_none());
+    }
+}
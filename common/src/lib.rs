@@ -0,0 +1,240 @@
+use std::fmt::Display;
+
+mod generator;
+mod registry;
+
+pub use generator::{generators, Generator, Reference, Rng};
+#[doc(hidden)]
+pub use inventory;
+pub use registry::{assert_days_registered, days, Answers, Registration};
+#[doc(hidden)]
+pub use registry::{day_number, parse, parse_only, trailing_number};
+
+/// How the work of a [`Solution`] is split between `parse` and the parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Steps {
+    /// `parse` prepares the input and each part solves its answer from it
+    Separate,
+    /// `parse` returns the input as is, each part doing all the work
+    Unparsed,
+    /// `parse` solves both parts, each part only returning its answer
+    Combined,
+}
+
+/// A single day of Advent of Code.
+///
+/// `parse` turns the puzzle input into whatever both parts need, and each part
+/// computes its answer from that parsed value. Days that already compute both
+/// answers while parsing simply return them from `parse`, and are [`Steps::Combined`].
+pub trait Solution {
+    type Parsed<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    const STEPS: Steps = Steps::Separate;
+
+    /// Number of parts, the last day of a year only having one
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Part1;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Part2;
+}
+
+/// Implement and [`register!`] [`Solution`] for the calling day module as `Day`.
+///
+/// - `solution!(u32, u64)` wraps the module's `part1(&str)` and `part2(&str)`.
+/// - `solution!(parse -> Grid; u32, u64)` wraps a `parse(&str)` returning a `Grid`, and the
+///   module's `part1(&Grid)` and `part2(&Grid)`. The parsed type may borrow the input as `'a`.
+/// - `solution!(parse_input -> (u32, u64))` wraps a `parse_input(&str)` that
+///   returns both answers at once.
+/// - `solution!(u32)` wraps the `part1(&str)` of a day without part 2.
+#[macro_export]
+macro_rules! solution {
+    ($part1:ty) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            type Parsed<'a> = &'a str;
+            type Part1 = $part1;
+            type Part2 = ::std::convert::Infallible;
+
+            const STEPS: $crate::Steps = $crate::Steps::Unparsed;
+            const PARTS: u8 = 1;
+
+            fn parse(input: &str) -> &str {
+                input
+            }
+
+            fn part1(input: &&str) -> $part1 {
+                part1(input)
+            }
+
+            fn part2(_input: &&str) -> ::std::convert::Infallible {
+                unreachable!("there is no part 2")
+            }
+        }
+
+        $crate::register!(Day);
+    };
+    ($part1:ty, $part2:ty) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            type Parsed<'a> = &'a str;
+            type Part1 = $part1;
+            type Part2 = $part2;
+
+            const STEPS: $crate::Steps = $crate::Steps::Unparsed;
+
+            fn parse(input: &str) -> &str {
+                input
+            }
+
+            fn part1(input: &&str) -> $part1 {
+                part1(input)
+            }
+
+            fn part2(input: &&str) -> $part2 {
+                part2(input)
+            }
+        }
+
+        $crate::register!(Day);
+    };
+    ($parse:ident -> $parsed:ty; $part1:ty, $part2:ty) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            type Parsed<'a> = $parsed;
+            type Part1 = $part1;
+            type Part2 = $part2;
+
+            fn parse(input: &str) -> Self::Parsed<'_> {
+                $parse(input)
+            }
+
+            fn part1(parsed: &Self::Parsed<'_>) -> $part1 {
+                part1(parsed)
+            }
+
+            fn part2(parsed: &Self::Parsed<'_>) -> $part2 {
+                part2(parsed)
+            }
+        }
+
+        $crate::register!(Day);
+    };
+    ($parse:ident -> ($part1:ty, $part2:ty)) => {
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            type Parsed<'a> = ($part1, $part2);
+            type Part1 = $part1;
+            type Part2 = $part2;
+
+            const STEPS: $crate::Steps = $crate::Steps::Combined;
+
+            fn parse(input: &str) -> ($part1, $part2) {
+                $parse(input)
+            }
+
+            fn part1(parsed: &($part1, $part2)) -> $part1 {
+                parsed.0.clone()
+            }
+
+            fn part2(parsed: &($part1, $part2)) -> $part2 {
+                parsed.1.clone()
+            }
+        }
+
+        $crate::register!(Day);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod by_part {
+        fn part1(input: &str) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &str) -> String {
+            input.to_uppercase()
+        }
+
+        solution!(usize, String);
+    }
+
+    mod parsed {
+        fn parse(input: &str) -> Vec<&str> {
+            input.lines().collect()
+        }
+
+        fn part1(lines: &[&str]) -> usize {
+            lines.len()
+        }
+
+        fn part2(lines: &[&str]) -> String {
+            lines.concat()
+        }
+
+        solution!(parse -> Vec<&'a str>; usize, String);
+    }
+
+    mod by_parse {
+        fn parse_input(input: &str) -> (usize, usize) {
+            (input.len(), input.lines().count())
+        }
+
+        solution!(parse_input -> (usize, usize));
+    }
+
+    mod single_part {
+        fn part1(input: &str) -> usize {
+            input.len()
+        }
+
+        solution!(usize);
+    }
+
+    #[test]
+    fn test_solution_by_part() {
+        let parsed = by_part::Day::parse("abc");
+
+        assert_eq!(by_part::Day::part1(&parsed), 3);
+        assert_eq!(by_part::Day::part2(&parsed), "ABC");
+        assert_eq!(by_part::Day::STEPS, Steps::Unparsed);
+        assert_eq!(by_part::Day::PARTS, 2);
+    }
+
+    #[test]
+    fn test_solution_parsed() {
+        let parsed = parsed::Day::parse("a\nb\nc");
+
+        assert_eq!(parsed::Day::part1(&parsed), 3);
+        assert_eq!(parsed::Day::part2(&parsed), "abc");
+        assert_eq!(parsed::Day::STEPS, Steps::Separate);
+    }
+
+    #[test]
+    fn test_solution_by_parse() {
+        let parsed = by_parse::Day::parse("a\nb\nc");
+
+        assert_eq!(by_parse::Day::part1(&parsed), 5);
+        assert_eq!(by_parse::Day::part2(&parsed), 3);
+        assert_eq!(by_parse::Day::STEPS, Steps::Combined);
+    }
+
+    #[test]
+    fn test_solution_single_part() {
+        let parsed = single_part::Day::parse("abc");
+
+        assert_eq!(single_part::Day::part1(&parsed), 3);
+        assert_eq!(single_part::Day::PARTS, 1);
+    }
+}
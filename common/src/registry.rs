@@ -0,0 +1,205 @@
+use std::{
+    hint::black_box,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::{Solution, Steps};
+
+/// The parsed input of a day, with the concrete [`Solution`] types erased
+pub trait Answers {
+    /// Solve part 1, with the time taken to solve it, not counting the formatting of its answer
+    fn part1(&self) -> (String, Duration);
+
+    /// Solve part 2, with the time taken to solve it, not counting the formatting of its answer
+    fn part2(&self) -> (String, Duration);
+
+    /// Solve part 1 without formatting its answer, to measure the part alone
+    fn run_part1(&self);
+
+    /// Solve part 2 without formatting its answer, to measure the part alone
+    fn run_part2(&self);
+}
+
+struct Parsed<'a, S: Solution>(S::Parsed<'a>);
+
+impl<S: Solution> Answers for Parsed<'_, S> {
+    fn part1(&self) -> (String, Duration) {
+        let start = Instant::now();
+        let answer = S::part1(&self.0);
+        let elapsed = start.elapsed();
+        (answer.to_string(), elapsed)
+    }
+
+    fn part2(&self) -> (String, Duration) {
+        let start = Instant::now();
+        let answer = S::part2(&self.0);
+        let elapsed = start.elapsed();
+        (answer.to_string(), elapsed)
+    }
+
+    fn run_part1(&self) {
+        black_box(S::part1(&self.0));
+    }
+
+    fn run_part2(&self) {
+        black_box(S::part2(&self.0));
+    }
+}
+
+#[doc(hidden)]
+pub fn parse<S: Solution + 'static>(input: &str) -> Box<dyn Answers + '_> {
+    Box::new(Parsed::<S>(S::parse(input)))
+}
+
+#[doc(hidden)]
+pub fn parse_only<S: Solution>(input: &str) {
+    black_box(S::parse(input));
+}
+
+/// A day registered with [`register!`](crate::register)
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    /// Path of the day module, e.g. `rust_2024::day1`
+    pub module: &'static str,
+    /// Root of the crate the day module lives in
+    pub manifest_dir: &'static str,
+    pub parse: for<'a> fn(&'a str) -> Box<dyn Answers + 'a>,
+    /// Parse an input and drop it, without boxing it like `parse`
+    pub parse_only: fn(&str),
+    pub steps: Steps,
+    /// Number of parts, `1` when there is no part 2
+    pub parts: u8,
+}
+
+inventory::collect!(Registration);
+
+impl Registration {
+    /// Name of the day module, e.g. `day1`
+    pub fn name(&self) -> &'static str {
+        self.module.rsplit("::").next().unwrap()
+    }
+
+    /// The `input.txt` stored next to the day module
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(self.manifest_dir)
+            .join("src")
+            .join(self.name())
+            .join("input.txt")
+    }
+}
+
+/// All the registered days of `year`, sorted by day
+pub fn days(year: u16) -> Vec<&'static Registration> {
+    let mut days: Vec<_> = inventory::iter::<Registration>
+        .into_iter()
+        .filter(|registration| registration.year == year)
+        .collect();
+    days.sort_by_key(|registration| registration.day);
+    days
+}
+
+/// Assert that every `day*` module in the `src` directory of `manifest_dir` is registered for
+/// `year`, from the tests of a year crate
+pub fn assert_days_registered(year: u16, manifest_dir: &str) {
+    let mut modules: Vec<String> = std::fs::read_dir(PathBuf::from(manifest_dir).join("src"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("day"))
+        .collect();
+    modules.sort();
+
+    let mut registered: Vec<&str> = days(year).iter().map(|day| day.name()).collect();
+    registered.sort();
+
+    assert_eq!(registered, modules, "registered days of {}", year);
+}
+
+/// Number at the end of `s`, e.g. `2024` for `rust-2024` and `1` for `rust_2023::day01`
+#[doc(hidden)]
+pub const fn trailing_number(s: &str) -> u16 {
+    let bytes = s.as_bytes();
+    let mut start = bytes.len();
+    while start > 0 && bytes[start - 1].is_ascii_digit() {
+        start -= 1;
+    }
+
+    let mut number = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        number = number * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    number
+}
+
+/// Day of the innermost `dayN` module of `module`, e.g. `7` for `rust_2024::day7::check`
+#[doc(hidden)]
+pub const fn day_number(module: &str) -> u8 {
+    let bytes = module.as_bytes();
+    let mut end = bytes.len();
+    while end > 0 {
+        let mut start = end;
+        while start > 0 && bytes[start - 1] != b':' {
+            start -= 1;
+        }
+        let segment = bytes.split_at(start).1.split_at(end - start).0;
+        if let [b'd', b'a', b'y', digits @ ..] = segment {
+            let mut number = 0;
+            let mut i = 0;
+            while i < digits.len() && digits[i].is_ascii_digit() {
+                number = number * 10 + (digits[i] - b'0');
+                i += 1;
+            }
+            if i > 0 && i == digits.len() {
+                return number;
+            }
+        }
+        end = start.saturating_sub(2);
+    }
+    0
+}
+
+/// Register a [`Solution`] so it is returned by [`days`].
+///
+/// The year and day are taken from the crate name (`rust-2024`) and the module name (`day1`).
+/// [`solution!`](crate::solution) already registers the `Day` it defines.
+#[macro_export]
+macro_rules! register {
+    ($solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::Registration {
+                year: $crate::trailing_number(env!("CARGO_PKG_NAME")),
+                day: $crate::day_number(module_path!()),
+                module: module_path!(),
+                manifest_dir: env!("CARGO_MANIFEST_DIR"),
+                parse: $crate::parse::<$solution>,
+                parse_only: $crate::parse_only::<$solution>,
+                steps: <$solution as $crate::Solution>::STEPS,
+                parts: <$solution as $crate::Solution>::PARTS,
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_number() {
+        assert_eq!(trailing_number("rust-2024"), 2024);
+        assert_eq!(trailing_number("rust_2023::day01"), 1);
+        assert_eq!(trailing_number("rust_2024::day17"), 17);
+        assert_eq!(trailing_number("aoc_common"), 0);
+    }
+
+    #[test]
+    fn test_day_number() {
+        assert_eq!(day_number("rust_2023::day01"), 1);
+        assert_eq!(day_number("rust_2024::day7::check"), 7);
+        assert_eq!(day_number("rust_2024::day17"), 17);
+        assert_eq!(day_number("aoc_common::tests::today"), 0);
+    }
+}
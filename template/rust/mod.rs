@@ -6,6 +6,8 @@ pub fn part2(input: &str) -> u32 {
     0
 }
 
+aoc_common::solution!(u32, u32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,161 @@
+use std::ops::{Index, IndexMut};
+
+use crate::grid::{check_width, ParseGridError};
+
+/// Grid of at most `N` × `N` cells stored inline, whose width and height are those of its input.
+///
+/// Rows are `[T; N]` arrays so `grid[i][j]` is as fast as with a plain `[[T; N]; N]`, and the
+/// cells beyond the width and height keep the fill value. Unlike [`Grid`](crate::Grid), nothing is
+/// allocated, for the days whose grid is copied or indexed in their hottest loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayGrid<T, const N: usize> {
+    cells: [[T; N]; N],
+    width: usize,
+    height: usize,
+}
+
+impl<T: Copy, const N: usize> ArrayGrid<T, N> {
+    /// Grid of `width` × `height` cells set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        assert!(
+            width <= N && height <= N,
+            "a {}x{} grid does not fit in {}x{}",
+            width,
+            height,
+            N,
+            N
+        );
+
+        ArrayGrid {
+            cells: [[fill; N]; N],
+            width,
+            height,
+        }
+    }
+
+    /// Grid of the lines of `input`, each byte turned into a cell by `cell` along with its position.
+    ///
+    /// Every line must be as wide as the first one, a blank line included, like with
+    /// [`Grid::parse`](crate::Grid::parse).
+    #[inline(always)]
+    pub fn parse(
+        input: &str,
+        fill: T,
+        mut cell: impl FnMut((usize, usize), u8) -> T,
+    ) -> Result<Self, ParseGridError> {
+        let width = input.lines().next().map_or(0, str::len);
+        if width > N {
+            return Err(ParseGridError::TooLarge { size: N });
+        }
+        let mut grid = Self::new(width, 0, fill);
+
+        for (i, line) in input.lines().enumerate() {
+            if i >= N {
+                return Err(ParseGridError::TooLarge { size: N });
+            }
+            check_width(i, line, width)?;
+            for (j, b) in line.bytes().enumerate() {
+                grid.cells[i][j] = cell((i, j), b);
+            }
+            grid.height = i + 1;
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cells of each row, without the ones beyond the width
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells[..self.height]
+            .iter()
+            .map(|row| &row[..self.width])
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ArrayGrid<T, N> {
+    type Output = [T; N];
+
+    #[inline(always)]
+    fn index(&self, i: usize) -> &[T; N] {
+        &self.cells[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ArrayGrid<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, i: usize) -> &mut [T; N] {
+        &mut self.cells[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut start = (0, 0);
+        let grid = ArrayGrid::<u8, 5>::parse(
+            indoc! {"
+                #..
+                .S#
+            "},
+            b' ',
+            |position, b| {
+                if b == b'S' {
+                    start = position;
+                }
+                b
+            },
+        )
+        .unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(start, (1, 1));
+        assert_eq!(grid[1][2], b'#');
+        assert_eq!(grid[1][3], b' ');
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [b"#..".as_slice(), b".S#".as_slice()]
+        );
+    }
+
+    #[test]
+    fn test_parse_too_large() {
+        let too_large = Err(ParseGridError::TooLarge { size: 2 });
+
+        assert_eq!(
+            ArrayGrid::<u8, 2>::parse("...\n", b' ', |_, b| b),
+            too_large
+        );
+        assert_eq!(
+            ArrayGrid::<u8, 2>::parse("..\n..\n..\n", b' ', |_, b| b),
+            too_large
+        );
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let parse = |input| ArrayGrid::<u8, 5>::parse(input, b' ', |_, b| b);
+        let ragged = |line, width| {
+            Err(ParseGridError::Ragged {
+                line,
+                width,
+                expected: 3,
+            })
+        };
+
+        assert_eq!(parse("abc\nde\n"), ragged(2, 2));
+        assert_eq!(parse("abc\ndefg\n"), ragged(2, 4));
+        assert_eq!(parse("abc\ndef\n\n"), ragged(3, 0));
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    collections::hash_map::{DefaultHasher, Entry},
+    hash::{BuildHasher, BuildHasherDefault, Hash},
+};
+
+use rustc_hash::FxHashMap;
+
+/// Where the states of a simulation start repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// First step of the cycle, the initial state being step 0
+    pub start: usize,
+    /// Number of steps before a state comes back
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Earliest step whose state is the state at step `n`
+    pub fn fast_forward(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Cycle of the states reached from `initial` with `step`, with Brent's algorithm.
+///
+/// Only two states are kept at once, at the cost of running more steps than [`detect`]. The
+/// states must end up repeating, or this never returns.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare runs while the tortoise waits at each power of two, until it comes back to it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare a cycle ahead, both meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Cycle of the states reached from `initial` with `step`, and every state before the first
+/// repeated one, the state at step `i` being `states[i]`.
+///
+/// Each step runs once. The states must end up repeating, or this never returns.
+pub fn detect<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let (states, cycle) = run(initial, step, usize::MAX);
+    (cycle.unwrap(), states)
+}
+
+/// State at step `n` from `initial`, skipping the repetitions once the states cycle
+pub fn nth<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    match run(initial, step, n) {
+        (states, Some(cycle)) => states[cycle.fast_forward(n)].clone(),
+        (mut states, None) => states.pop().unwrap(),
+    }
+}
+
+/// States from `initial` until one repeats, or until step `limit` included
+fn run<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = FxHashMap::from_iter([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+
+    while states.len() <= limit {
+        match seen.entry(step(states.last().unwrap())) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let length = states.len() - start;
+                return (states, Some(Cycle { start, length }));
+            }
+            Entry::Vacant(entry) => {
+                states.push(entry.key().clone());
+                entry.insert(states.len() - 1);
+            }
+        }
+    }
+
+    (states, None)
+}
+
+/// Cycle of the states of `state` stepped in place with `step`, leaving it at the end of the
+/// cycle, in the same state as at its start.
+///
+/// Only a hash of each state is kept, so the states are not cloned but two states with the same
+/// hash are taken to be the same. The states must end up repeating, or this never returns.
+pub fn detect_in_place<S: Hash>(state: &mut S, step: impl FnMut(&mut S)) -> Cycle {
+    run_in_place(state, step, usize::MAX).unwrap()
+}
+
+/// Step `state` in place `n` times, skipping the repetitions once the states cycle, keeping only
+/// a hash of each state like [`detect_in_place`]
+pub fn advance<S: Hash>(state: &mut S, mut step: impl FnMut(&mut S), n: usize) {
+    if let Some(cycle) = run_in_place(state, &mut step, n) {
+        // the state is back at the start of the cycle
+        for _ in cycle.start..cycle.fast_forward(n) {
+            step(state);
+        }
+    }
+}
+
+/// Step `state` in place until it repeats, or `limit` times
+fn run_in_place<S: Hash>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    limit: usize,
+) -> Option<Cycle> {
+    let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    let mut seen = FxHashMap::default();
+
+    for steps in 0..limit {
+        match seen.entry(hasher.hash_one(&*state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Some(Cycle {
+                    start,
+                    length: steps - start,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+        step(state);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, then 3, 4, 5, 6 forever
+    fn step(&state: &u32) -> u32 {
+        if state < 6 {
+            state + 1
+        } else {
+            3
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let cycle = Cycle {
+            start: 3,
+            length: 4,
+        };
+
+        assert_eq!(brent(0, step), cycle);
+        assert_eq!(detect(0, step), (cycle, vec![0, 1, 2, 3, 4, 5, 6]));
+        assert_eq!(
+            brent(4, step),
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        assert_eq!(
+            detect(7, |_| 7).0,
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        let cycle = detect(0, step).0;
+
+        assert_eq!(cycle.fast_forward(2), 2);
+        assert_eq!(cycle.fast_forward(7), 3);
+        assert_eq!(cycle.fast_forward(1_000_000_000), 4);
+        assert_eq!(nth(0, step, 1_000_000_000), 4);
+        assert_eq!(nth(0, step, 5), 5);
+
+        let mut steps = 0;
+        let count = |&state: &u64| {
+            steps += 1;
+            state + 1
+        };
+        assert_eq!(nth(0, count, 10), 10);
+        assert_eq!(steps, 10);
+    }
+
+    #[test]
+    fn test_in_place() {
+        let step = |state: &mut u32| *state = self::step(state);
+
+        let mut state = 0;
+        assert_eq!(
+            detect_in_place(&mut state, step),
+            Cycle {
+                start: 3,
+                length: 4
+            }
+        );
+        assert_eq!(state, 3);
+
+        for (n, expected) in [(2, 2), (7, 3), (1_000_000_000, 4)] {
+            let mut state = 0;
+            advance(&mut state, step, n);
+            assert_eq!(state, expected);
+        }
+
+        let (mut state, mut steps) = (0u64, 0);
+        advance(
+            &mut state,
+            |state| {
+                steps += 1;
+                *state += 1;
+            },
+            10,
+        );
+        assert_eq!((state, steps), (10, 10));
+    }
+}
@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+/// A move of at most one cell along each axis
+pub trait Direction: Copy {
+    /// Row and column offsets of the move, as taken by [`Point::step`](crate::Point::step)
+    fn offset(self) -> (isize, isize);
+}
+
+/// Turning, reversing and bitflags of a direction enum whose variants are listed clockwise
+macro_rules! clockwise {
+    ($name:ident, [$($variant:ident => $offset:expr),+ $(,)?]) => {
+        impl $name {
+            /// Every direction, clockwise from up
+            pub const ALL: [$name; [$($name::$variant),+].len()] = [$($name::$variant),+];
+
+            #[inline]
+            fn from_index(index: usize) -> Self {
+                Self::ALL[index % Self::ALL.len()]
+            }
+
+            /// Next direction clockwise
+            #[inline]
+            pub fn turn_right(self) -> Self {
+                Self::from_index(self as usize + 1)
+            }
+
+            /// Next direction counterclockwise
+            #[inline]
+            pub fn turn_left(self) -> Self {
+                Self::from_index(self as usize + Self::ALL.len() - 1)
+            }
+
+            #[inline]
+            pub fn reverse(self) -> Self {
+                Self::from_index(self as usize + Self::ALL.len() / 2)
+            }
+
+            /// Flag of the direction, to store a set of directions in a `u8`
+            #[inline]
+            pub const fn bit(self) -> u8 {
+                1 << self as u8
+            }
+
+            /// Directions whose flag is set in `bits`, clockwise from up
+            pub fn from_bits(bits: u8) -> impl Iterator<Item = Self> {
+                Self::ALL
+                    .into_iter()
+                    .filter(move |direction| bits & direction.bit() != 0)
+            }
+        }
+
+        impl Direction for $name {
+            #[inline]
+            fn offset(self) -> (isize, isize) {
+                match self {
+                    $($name::$variant => $offset),+
+                }
+            }
+        }
+    };
+}
+
+/// Horizontal or vertical direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+clockwise!(Direction4, [
+    Up => (-1, 0),
+    Right => (0, 1),
+    Down => (1, 0),
+    Left => (0, -1),
+]);
+
+/// Parse an arrow (`^v<>`), a cardinal point (`NSEW`) or an initial (`UDLR`)
+impl TryFrom<u8> for Direction4 {
+    type Error = String;
+
+    fn try_from(b: u8) -> Result<Self, String> {
+        match b {
+            b'^' | b'N' | b'U' => Ok(Direction4::Up),
+            b'>' | b'E' | b'R' => Ok(Direction4::Right),
+            b'v' | b'S' | b'D' => Ok(Direction4::Down),
+            b'<' | b'W' | b'L' => Ok(Direction4::Left),
+            _ => Err(format!("invalid direction {:?}", b as char)),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.as_bytes() {
+            &[b] => Direction4::try_from(b),
+            _ => Err(format!("invalid direction {:?}", s)),
+        }
+    }
+}
+
+/// Horizontal, vertical or diagonal direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+clockwise!(Direction8, [
+    Up => (-1, 0),
+    UpRight => (-1, 1),
+    Right => (0, 1),
+    DownRight => (1, 1),
+    Down => (1, 0),
+    DownLeft => (1, -1),
+    Left => (0, -1),
+    UpLeft => (-1, -1),
+]);
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Parse anything a [`Direction4`] parses, or two cardinal points such as `NE`
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Right.reverse(), Direction4::Left);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            let (di, dj) = direction.offset();
+            assert_eq!(direction.reverse().offset(), (-di, -dj));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("^".parse(), Ok(Direction4::Up));
+        assert_eq!("E".parse(), Ok(Direction4::Right));
+        assert_eq!(Direction4::try_from(b'D'), Ok(Direction4::Down));
+        assert_eq!(
+            "<>v"
+                .bytes()
+                .map(Direction4::try_from)
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(vec![Direction4::Left, Direction4::Right, Direction4::Down])
+        );
+        assert_eq!(
+            "x".parse::<Direction4>(),
+            Err("invalid direction 'x'".to_string())
+        );
+        assert!("UU".parse::<Direction4>().is_err());
+
+        assert_eq!("SW".parse(), Ok(Direction8::DownLeft));
+        assert_eq!("L".parse(), Ok(Direction8::Left));
+        assert!("NS".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn test_bits() {
+        let bits = Direction4::Up.bit() | Direction4::Left.bit();
+
+        assert_eq!(bits, 0b1001);
+        assert_eq!(
+            Direction4::from_bits(bits).collect::<Vec<_>>(),
+            [Direction4::Up, Direction4::Left]
+        );
+        assert_eq!(Direction8::UpLeft.bit(), 0b1000_0000);
+        assert_eq!(Direction8::from_bits(0).count(), 0);
+    }
+}
@@ -0,0 +1,462 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+/// Directed graph of the nodes `0..len`, stored as the successors of each node
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    successors: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Graph of `len` nodes without edges
+    pub fn new(len: usize) -> Self {
+        Graph {
+            successors: vec![vec![]; len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.successors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    /// Add an edge from `a` to `b`, growing the graph to contain both nodes
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        let len = a.max(b) + 1;
+        if self.len() < len {
+            self.successors.resize(len, vec![]);
+        }
+        self.successors[a].push(b);
+    }
+
+    /// Add an edge from `a` to `b` and one from `b` to `a`
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize) {
+        self.add_edge(a, b);
+        self.add_edge(b, a);
+    }
+
+    pub fn successors(&self, node: usize) -> &[usize] {
+        &self.successors[node]
+    }
+
+    pub fn contains_edge(&self, a: usize, b: usize) -> bool {
+        a < self.len() && self.successors[a].contains(&b)
+    }
+
+    /// Graph of the edges between `nodes`, the node `nodes[i]` becoming `i`
+    pub fn induced(&self, nodes: &[usize]) -> Graph {
+        let index: FxHashMap<usize, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+        let mut graph = Graph::new(nodes.len());
+        for (i, &node) in nodes.iter().enumerate() {
+            for successor in self.successors(node) {
+                if let Some(&j) = index.get(successor) {
+                    graph.add_edge(i, j);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Largest set of nodes all connected to each other, sorted, the edges being undirected
+    ///
+    /// Uses the Bron–Kerbosch algorithm with pivoting, on bitsets of the neighbours of each node.
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        // an undirected edge may be given in a single direction
+        let mut neighbours = vec![Bits::new(self.len()); self.len()];
+        for node in 0..self.len() {
+            for &successor in self.successors(node) {
+                if successor != node {
+                    neighbours[node].insert(successor);
+                    neighbours[successor].insert(node);
+                }
+            }
+        }
+
+        let mut all = Bits::new(self.len());
+        (0..self.len()).for_each(|node| all.insert(node));
+        let mut best = vec![];
+        bron_kerbosch(
+            &neighbours,
+            &mut vec![],
+            all,
+            Bits::new(self.len()),
+            &mut best,
+        );
+        best.sort_unstable();
+        best
+    }
+
+    /// Nodes connected to each other whatever the direction of the edges, each component sorted
+    /// and the components sorted by their first node
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut parents: Vec<usize> = (0..self.len()).collect();
+        fn root(parents: &mut [usize], mut node: usize) -> usize {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+
+        for node in 0..self.len() {
+            for &successor in self.successors(node) {
+                let (a, b) = (root(&mut parents, node), root(&mut parents, successor));
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut components: Vec<Vec<usize>> = vec![];
+        let mut component_of = vec![usize::MAX; self.len()];
+        for node in 0..self.len() {
+            let root = root(&mut parents, node);
+            if component_of[root] == usize::MAX {
+                component_of[root] = components.len();
+                components.push(vec![]);
+            }
+            components[component_of[root]].push(node);
+        }
+        components
+    }
+
+    /// Nodes ordered so that every edge goes forward, or the nodes of a cycle from its smallest
+    /// node, each one having an edge to the next and the last one to the first
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        let mut in_degrees = vec![0; self.len()];
+        for successors in &self.successors {
+            successors
+                .iter()
+                .for_each(|&successor| in_degrees[successor] += 1);
+        }
+
+        // Kahn's algorithm, taking the smallest available node first
+        let mut available: BinaryHeap<Reverse<usize>> = (0..self.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(node)) = available.pop() {
+            order.push(node);
+            for &successor in self.successors(node) {
+                in_degrees[successor] -= 1;
+                if in_degrees[successor] == 0 {
+                    available.push(Reverse(successor));
+                }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // every node left has a predecessor left, so walking backwards from any of them loops
+        let mut predecessor = vec![usize::MAX; self.len()];
+        for node in (0..self.len()).filter(|&node| in_degrees[node] > 0) {
+            for &successor in self.successors(node) {
+                if in_degrees[successor] > 0 {
+                    predecessor[successor] = node;
+                }
+            }
+        }
+        let mut seen = vec![false; self.len()];
+        let mut node = (0..self.len()).find(|&node| in_degrees[node] > 0).unwrap();
+        while !seen[node] {
+            seen[node] = true;
+            node = predecessor[node];
+        }
+        let mut cycle = vec![node];
+        let mut previous = predecessor[node];
+        while previous != node {
+            cycle.push(previous);
+            previous = predecessor[previous];
+        }
+        cycle.reverse();
+        let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+        cycle.rotate_left(smallest);
+        Err(cycle)
+    }
+
+    /// Sets of nodes that can all reach each other, each one sorted, with Tarjan's algorithm
+    ///
+    /// A component comes before the components it has an edge from, so the last one has no
+    /// incoming edge from another component.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut next_index = 0;
+
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+            // the node being explored and the position of its next successor to explore
+            let mut calls = vec![(root, 0)];
+            while let Some(&mut (node, ref mut next)) = calls.last_mut() {
+                if *next == 0 {
+                    index[node] = next_index;
+                    low[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(&successor) = self.successors(node).get(*next) {
+                    *next += 1;
+                    if index[successor] == UNVISITED {
+                        calls.push((successor, 0));
+                    } else if on_stack[successor] {
+                        low[node] = low[node].min(index[successor]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if low[node] == index[node] {
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+}
+
+/// Ids of labelled nodes, given in the order the labels are first seen
+#[derive(Debug, Clone)]
+pub struct Labels<K> {
+    ids: FxHashMap<K, usize>,
+    labels: Vec<K>,
+}
+
+impl<K> Default for Labels<K> {
+    fn default() -> Self {
+        Labels {
+            ids: FxHashMap::default(),
+            labels: vec![],
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> Labels<K> {
+    /// Id of `label`, a new one if it was never seen
+    pub fn id(&mut self, label: K) -> usize {
+        let next = self.labels.len();
+        *self.ids.entry(label).or_insert_with_key(|label| {
+            self.labels.push(label.clone());
+            next
+        })
+    }
+
+    pub fn get(&self, label: &K) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: usize) -> &K {
+        &self.labels[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+/// Set of nodes, one bit per node
+#[derive(Debug, Clone)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Bits(vec![0; len.div_ceil(64)])
+    }
+
+    fn insert(&mut self, node: usize) {
+        self.0[node / 64] |= 1 << (node % 64);
+    }
+
+    fn remove(&mut self, node: usize) {
+        self.0[node / 64] &= !(1 << (node % 64));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn and(&self, other: &Bits) -> Bits {
+        Bits(self.0.iter().zip(&other.0).map(|(a, b)| a & b).collect())
+    }
+
+    fn and_not(&self, other: &Bits) -> Bits {
+        Bits(self.0.iter().zip(&other.0).map(|(a, b)| a & !b).collect())
+    }
+
+    fn or(&self, other: &Bits) -> Bits {
+        Bits(self.0.iter().zip(&other.0).map(|(a, b)| a | b).collect())
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| i * 64 + bit)
+        })
+    }
+}
+
+/// Grow `clique` with the `candidates`, none of the `excluded` being connected to all of it
+fn bron_kerbosch(
+    neighbours: &[Bits],
+    clique: &mut Vec<usize>,
+    mut candidates: Bits,
+    mut excluded: Bits,
+    best: &mut Vec<usize>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > best.len() {
+            *best = clique.clone();
+        }
+        return;
+    }
+    if clique.len() + candidates.len() <= best.len() {
+        return;
+    }
+
+    // every maximal clique contains the pivot or one of its non-neighbours
+    let pivot = candidates
+        .or(&excluded)
+        .iter()
+        .max_by_key(|&node| candidates.and(&neighbours[node]).len())
+        .unwrap();
+    for node in candidates
+        .and_not(&neighbours[pivot])
+        .iter()
+        .collect::<Vec<_>>()
+    {
+        clique.push(node);
+        bron_kerbosch(
+            neighbours,
+            clique,
+            candidates.and(&neighbours[node]),
+            excluded.and(&neighbours[node]),
+            best,
+        );
+        clique.pop();
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_edges(edges: &[(usize, usize)]) -> Graph {
+        let mut graph = Graph::default();
+        for &(a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_maximum_clique() {
+        // a greedy pass from node 0 only finds the triangle 0-1-2
+        let mut graph = Graph::new(7);
+        for (a, b) in [
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (3, 4),
+            (3, 5),
+            (4, 5),
+            (1, 5),
+        ] {
+            graph.add_undirected_edge(a, b);
+        }
+
+        assert_eq!(graph.maximum_clique(), [1, 3, 4, 5]);
+        // edges given in one direction only count as well
+        assert_eq!(
+            from_edges(&[(2, 0), (0, 1), (1, 2)]).maximum_clique(),
+            [0, 1, 2]
+        );
+        assert_eq!(Graph::new(2).maximum_clique().len(), 1);
+        assert!(Graph::default().maximum_clique().is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let mut graph = from_edges(&[(0, 3), (4, 1), (3, 5)]);
+        graph.add_edge(2, 2);
+
+        assert_eq!(graph.components(), [vec![0, 3, 5], vec![1, 4], vec![2]]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let dag = from_edges(&[(3, 1), (1, 0), (3, 2), (2, 0)]);
+        assert_eq!(dag.topological_sort(), Ok(vec![3, 1, 2, 0]));
+
+        let cyclic = from_edges(&[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+        assert_eq!(cyclic.topological_sort(), Err(vec![1, 2, 3]));
+        assert_eq!(from_edges(&[(0, 0)]).topological_sort(), Err(vec![0]));
+
+        assert_eq!(
+            cyclic.induced(&[4, 3, 0]).topological_sort(),
+            Ok(vec![1, 0, 2])
+        );
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 4)]);
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            [vec![3, 4], vec![0, 1, 2], vec![5]]
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut labels = Labels::default();
+
+        assert_eq!(labels.id("kh"), 0);
+        assert_eq!(labels.id("tc"), 1);
+        assert_eq!(labels.id("kh"), 0);
+        assert_eq!(labels.get(&"tc"), Some(1));
+        assert_eq!(labels.get(&"qp"), None);
+        assert_eq!(*labels.label(1), "tc");
+        assert_eq!(labels.len(), 2);
+    }
+}
@@ -0,0 +1,524 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, Direction4, Direction8, Point};
+
+/// Input that cannot be parsed as a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// The line numbered `line` from 1 is not as wide as the first one
+    Ragged {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// The input has more than `size` lines or columns, for an
+    /// [`ArrayGrid`](crate::ArrayGrid) of `size` × `size` cells
+    TooLarge { size: usize },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGridError::Ragged {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "line {} is {} cells wide instead of {}",
+                line, width, expected
+            ),
+            ParseGridError::TooLarge { size } => {
+                write!(f, "the input does not fit in {}x{}", size, size)
+            }
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// Check that the line at `index` of an input is as wide as its first line, of `width`
+#[inline(always)]
+pub(crate) fn check_width(index: usize, line: &str, width: usize) -> Result<(), ParseGridError> {
+    if line.len() == width {
+        Ok(())
+    } else {
+        Err(ParseGridError::Ragged {
+            line: index + 1,
+            width: line.len(),
+            expected: width,
+        })
+    }
+}
+
+/// Rectangular grid of cells stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` × `height` cells set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Grid of the lines of `input`, each byte turned into a cell by `cell` along with its position.
+    ///
+    /// Every line must be as wide as the first one, a blank line included.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Point<usize>, u8) -> T,
+    ) -> Result<Self, ParseGridError> {
+        let width = input.lines().next().map_or(0, str::len);
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            check_width(y, line, width)?;
+            cells.extend(
+                line.bytes()
+                    .enumerate()
+                    .map(|(x, b)| cell(Point::new(x, y), b)),
+            );
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point<usize>) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.y * self.width + position.x])
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.y * self.width + position.x])
+    }
+
+    /// Cell at a position of the grid repeated infinitely in every direction, `None` only when
+    /// the grid is empty
+    pub fn get_wrapping(&self, position: Point<isize>) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        Some(
+            &self[Point::new(
+                position.x.rem_euclid(self.width as isize) as usize,
+                position.y.rem_euclid(self.height as isize) as usize,
+            )],
+        )
+    }
+
+    /// Position one step away in `direction`, if still in the grid
+    pub fn step(&self, position: Point<usize>, direction: impl Direction) -> Option<Point<usize>> {
+        position
+            .checked_step(direction, self.width, self.height)
+            .filter(|&position| self.contains(position))
+    }
+
+    /// Positions of the neighbours above, right, below and left of a cell that are in the grid
+    pub fn neighbours4(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Positions of the neighbours of a cell that are in the grid, diagonals included
+    pub fn neighbours8(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Positions from `position` (excluded) stepping in `direction` until the edge of the grid
+    pub fn ray<'a>(
+        &'a self,
+        position: Point<usize>,
+        direction: impl Direction + 'a,
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        std::iter::successors(self.step(position, direction), move |&position| {
+            self.step(position, direction)
+        })
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "no column {} in a grid {} wide",
+            x,
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Cells of each diagonal going down and right, from the bottom left one to the top right one
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (1..self.height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((0..self.width).map(|x| Point::new(x, 0)));
+        self.lines(starts, Direction8::DownRight)
+    }
+
+    /// Cells of each diagonal going down and left, from the top left one to the bottom right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..self.height).map(move |y| Point::new(last, y)));
+        self.lines(starts, Direction8::DownLeft)
+    }
+
+    /// Cells from each of `starts` (included) stepping in `direction` until the edge of the grid,
+    /// the starts out of the grid being skipped as an empty grid has none in it
+    fn lines(
+        &self,
+        starts: impl Iterator<Item = Point<usize>>,
+        direction: Direction8,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        starts
+            .filter(|&start| self.contains(start))
+            .map(move |start| {
+                std::iter::once(start)
+                    .chain(self.ray(start, direction))
+                    .map(|position| &self[position])
+            })
+    }
+
+    /// Position of the first cell matching `predicate`, row by row
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Positions of every cell matching `predicate`, row by row
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Grid whose rows are the columns of this one
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    /// Grid turned a quarter clockwise, the left column becoming the top row
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        // `source` is only called for the cells of a grid that is not empty
+        self.rearrange(self.height, self.width, |p| {
+            Point::new(p.y, self.height - 1 - p.x)
+        })
+    }
+
+    /// Grid turned a quarter counterclockwise, the top row becoming the left column
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |p| {
+            Point::new(self.width - 1 - p.y, p.x)
+        })
+    }
+
+    /// Grid of `width` × `height` cells, each copied from the position given by `source`
+    fn rearrange(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(Point<usize>) -> Point<usize>,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{} is out of a {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{} is out of a {}x{} grid", position, width, height))
+    }
+}
+
+/// One line per row, e.g. `grid.map(|&b| b as char)` to print a grid of bytes
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const INPUT: &str = indoc! {"
+        abc
+        def
+    "};
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, |_, b| b as char).unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut positions = vec![];
+        let grid = Grid::parse(INPUT, |position, b| {
+            positions.push(position);
+            b
+        })
+        .unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(positions, grid.positions().collect::<Vec<_>>());
+        assert_eq!(grid[Point::new(0, 1)], b'd');
+        assert_eq!(grid.to_string(), "979899\n100101102\n");
+        assert_eq!(chars(INPUT).to_string(), INPUT);
+        assert_eq!(Grid::parse("", |_, b| b), Ok(Grid::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let ragged = |line, width| {
+            Err(ParseGridError::Ragged {
+                line,
+                width,
+                expected: 3,
+            })
+        };
+
+        assert_eq!(Grid::parse("abc\nde\n", |_, b| b), ragged(2, 2));
+        assert_eq!(Grid::parse("abc\ndefg\n", |_, b| b), ragged(2, 4));
+        assert_eq!(Grid::parse("abc\ndef\n\n", |_, b| b), ragged(3, 0));
+        assert_eq!(
+            ragged(2, 2).unwrap_err().to_string(),
+            "line 2 is 2 cells wide instead of 3"
+        );
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = chars(INPUT);
+
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        *grid.get_mut(Point::new(0, 0)).unwrap() = 'A';
+        grid[Point::new(1, 1)] = 'E';
+        assert_eq!(grid.to_string(), "Abc\ndEf\n");
+
+        assert_eq!(grid.get_wrapping(Point::new(-1, -1)), Some(&'f'));
+        assert_eq!(grid.get_wrapping(Point::new(7, 4)), Some(&'b'));
+        assert_eq!(
+            grid.step(Point::new(0, 0), Direction8::DownRight),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(grid.step(Point::new(0, 0), Direction4::Up), None);
+        assert_eq!(grid.step(Point::new(2, 1), Direction4::Right), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "0,2 is out of a 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = chars(INPUT)[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let points = |points: &[(usize, usize)]| {
+            points
+                .iter()
+                .map(|&(x, y)| Point::new(x, y))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            grid.neighbours4(Point::new(1, 1)).collect::<Vec<_>>(),
+            points(&[(1, 0), (2, 1), (1, 2), (0, 1)])
+        );
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            points(&[(1, 0), (0, 1)])
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8(Point::new(2, 2)).collect::<Vec<_>>(),
+            points(&[(2, 1), (1, 2), (1, 1)])
+        );
+        assert_eq!(
+            grid.ray(Point::new(0, 2), Direction8::UpRight)
+                .collect::<Vec<_>>(),
+            points(&[(1, 1), (2, 0)])
+        );
+        assert_eq!(grid.ray(Point::new(0, 0), Direction4::Left).count(), 0);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = chars(INPUT);
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(collect(grid.rows().map(|row| row.iter())), ["abc", "def"]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = chars(indoc! {"
+            #.#
+            .#.
+        "});
+
+        assert_eq!(grid.find(|&c| c == '.'), Some(Point::new(1, 0)));
+        assert_eq!(grid.find(|&c| c == 'S'), None);
+        assert_eq!(
+            grid.find_all(|&c| c == '#').collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let grid = chars(INPUT);
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        for grid in [
+            Grid::new(0, 0, 'a'),
+            Grid::new(0, 3, 'a'),
+            Grid::new(2, 0, 'a'),
+        ] {
+            assert_eq!(grid.get_wrapping(Point::new(1, 1)), None);
+            assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 0);
+            assert_eq!(grid.diagonals().count(), 0);
+            assert_eq!(grid.anti_diagonals().count(), 0);
+            assert_eq!(grid.transpose().rotate_clockwise(), grid);
+            assert_eq!(grid.rotate_counterclockwise().transpose(), grid);
+            assert_eq!(grid.to_string().trim(), "");
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod array_grid;
+pub mod cycle;
+pub mod direction;
+pub mod graph;
+pub mod grid;
+pub mod point;
+pub mod search;
+
+pub use array_grid::ArrayGrid;
+pub use direction::{Direction, Direction4, Direction8};
+pub use grid::{Grid, ParseGridError};
+pub use point::Point;
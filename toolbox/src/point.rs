@@ -0,0 +1,191 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+};
+
+use crate::direction::Direction;
+
+/// Point of the plane, `x` growing to the right and `y` growing downwards like the rows of a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    /// Point one step away in `direction`
+    #[inline]
+    pub fn step(self, direction: impl Direction) -> Self {
+        let (dy, dx) = direction.offset();
+        Point::new(shift(self.x, dx), shift(self.y, dy))
+    }
+
+    /// Point one step away in `direction`, if it stays within `0..width` and `0..height`
+    #[inline]
+    pub fn checked_step(self, direction: impl Direction, width: T, height: T) -> Option<Self> {
+        let (dy, dx) = direction.offset();
+        Some(Point::new(
+            checked_shift(self.x, dx, width)?,
+            checked_shift(self.y, dy, height)?,
+        ))
+    }
+
+    /// Number of horizontal and vertical steps between both points
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+#[inline]
+fn shift<T>(value: T, delta: isize) -> T
+where
+    T: Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    match delta {
+        -1 => value - T::from(1),
+        1 => value + T::from(1),
+        _ => value,
+    }
+}
+
+#[inline]
+fn checked_shift<T>(value: T, delta: isize, bound: T) -> Option<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + From<u8>,
+{
+    let one = T::from(1);
+    match delta {
+        -1 if value >= one => Some(value - one),
+        1 if value + one < bound => Some(value + one),
+        0 => Some(value),
+        _ => None,
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// The point of the row and column of a grid cell
+impl From<(usize, usize)> for Point<usize> {
+    fn from((i, j): (usize, usize)) -> Self {
+        Point::new(j, i)
+    }
+}
+
+/// The row and column of the grid cell at a point
+impl From<Point<usize>> for (usize, usize) {
+    fn from(point: Point<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::{Direction4, Direction8};
+
+    #[test]
+    fn test_step() {
+        let point = Point::new(0i32, 0);
+
+        assert_eq!(point.step(Direction4::Up), Point::new(0, -1));
+        assert_eq!(point.step(Direction8::DownLeft), Point::new(-1, 1));
+        assert_eq!(
+            Point::new(2usize, 5).step(Direction4::Right),
+            Point::new(3, 5)
+        );
+    }
+
+    #[test]
+    fn test_checked_step() {
+        let corner = Point::new(2usize, 0);
+
+        assert_eq!(corner.checked_step(Direction4::Up, 3, 2), None);
+        assert_eq!(corner.checked_step(Direction4::Right, 3, 2), None);
+        assert_eq!(
+            corner.checked_step(Direction8::DownLeft, 3, 2),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(
+            Point::new(0i64, 1).checked_step(Direction4::Down, 3, 2),
+            None
+        );
+        assert_eq!(
+            Point::new(1i64, 1).checked_step(Direction4::Up, 3, 2),
+            Some(Point::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point::new(1i32, -2);
+
+        assert_eq!(point.manhattan(Point::new(-3, 4)), 10);
+        assert_eq!(Point::new(7u32, 1).manhattan(Point::new(2, 3)), 7);
+        assert_eq!(point + Point::new(2, 2), Point::new(3, 0));
+        assert_eq!(point - Point::new(2, 2), Point::new(-1, -4));
+        assert_eq!(point * 3, Point::new(3, -6));
+        point += Point::new(1, 1);
+        point -= Point::new(0, 2);
+        assert_eq!(point.to_string(), "2,-3");
+    }
+
+    #[test]
+    fn test_grid_positions() {
+        let point = Point::from((1, 4));
+
+        assert_eq!(point, Point::new(4, 1));
+        assert_eq!(<(usize, usize)>::from(point), (1, 4));
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Cheapest path found by a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// States from the start to the goal, both included
+    pub states: Vec<S>,
+    /// Number of states expanded before reaching the goal, the goal included
+    pub visited: usize,
+}
+
+/// States reached so far, each with the index of the one it was reached from
+struct Tree<S> {
+    nodes: Vec<(S, usize)>,
+}
+
+impl<S: Clone> Tree<S> {
+    fn new(start: S) -> Self {
+        Tree {
+            nodes: vec![(start, usize::MAX)],
+        }
+    }
+
+    fn push(&mut self, state: S, parent: usize) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut states = vec![];
+        while index != usize::MAX {
+            let (state, parent) = &self.nodes[index];
+            states.push(state.clone());
+            index = *parent;
+        }
+        states.reverse();
+        states
+    }
+}
+
+/// Path with the fewest steps from `start` to a state matching `is_goal`, every step costing 1
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut tree = Tree::new(start.clone());
+    let mut seen = FxHashSet::default();
+    seen.insert(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    let mut visited = 0;
+
+    while let Some((cost, index)) = queue.pop_front() {
+        visited += 1;
+        let state = tree.nodes[index].0.clone();
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: tree.path(index),
+                visited,
+            });
+        }
+
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                queue.push_back((cost + 1, tree.push(next, index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path from `start` to a state matching `is_goal`, `successors` giving the cost of
+/// each step.
+///
+/// The costs must not be negative, `C::default()` being zero.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Cheapest path like [`dijkstra`], exploring first the states whose cost so far plus
+/// `heuristic` is the lowest.
+///
+/// The heuristic must never overestimate the cost left to reach a goal, e.g. the Manhattan
+/// distance to the goal when each step costs at least 1.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut costs = FxHashMap::default();
+    costs.insert(start.clone(), C::default());
+    let mut tree = Tree::new(start);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = tree.nodes[index].0.clone();
+        // a cheaper way to this state was found after this one was queued
+        if costs[&state] < cost {
+            continue;
+        }
+        visited += 1;
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: tree.path(index),
+                visited,
+            });
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match costs.entry(next.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(next_cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+            }
+            let estimate = next_cost + heuristic(&next);
+            queue.push(Reverse((estimate, next_cost, tree.push(next, index))));
+        }
+    }
+
+    None
+}
+
+/// Every cheapest path to the goals, as the states each state is reached from at its lowest cost
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    pub cost: C,
+    /// Goals reached at the lowest cost
+    pub goals: Vec<S>,
+    /// Number of states expanded before every goal was reached
+    pub visited: usize,
+    predecessors: FxHashMap<S, (C, Vec<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C> ShortestPaths<S, C> {
+    /// States preceding `state` on the cheapest paths to it
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors
+            .get(state)
+            .map_or(&[], |(_, predecessors)| predecessors)
+    }
+
+    /// Every state on at least one of the cheapest paths, the start and goals included
+    pub fn states(&self) -> FxHashSet<S> {
+        let mut states: FxHashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for predecessor in self.predecessors(&state) {
+                if states.insert(predecessor.clone()) {
+                    stack.push(predecessor.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// One of the cheapest paths, from the start to the first goal
+    pub fn path(&self) -> Vec<S> {
+        let mut states = vec![self.goals[0].clone()];
+        while let Some(predecessor) = self.predecessors(states.last().unwrap()).first() {
+            states.push(predecessor.clone());
+        }
+        states.reverse();
+        states
+    }
+}
+
+/// Every cheapest path from `start` to the states matching `is_goal`, searched like [`dijkstra`]
+pub fn all_shortest_paths<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut states = vec![start.clone()];
+    let mut queue = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut predecessors = FxHashMap::default();
+    predecessors.insert(start, (C::default(), vec![]));
+    let mut goals = vec![];
+    let mut goal_cost = None;
+    let mut visited = 0;
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        let state = states[index].clone();
+        // a cheaper way to this state was found after this one was queued
+        if predecessors[&state].0 < cost {
+            continue;
+        }
+        visited += 1;
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match predecessors.entry(next.clone()) {
+                Entry::Occupied(mut entry) => {
+                    let (best, from) = entry.get_mut();
+                    if next_cost == *best {
+                        // the start stays without predecessors when steps of zero cost lead
+                        // back to it, so that walking back from a goal ends there
+                        if !from.is_empty() {
+                            from.push(state.clone());
+                        }
+                        continue;
+                    } else if next_cost > *best {
+                        continue;
+                    }
+                    *best = next_cost;
+                    *from = vec![state.clone()];
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, vec![state.clone()]));
+                }
+            }
+            states.push(next);
+            queue.push(Reverse((next_cost, states.len() - 1)));
+        }
+    }
+
+    Some(ShortestPaths {
+        cost: goal_cost?,
+        goals,
+        visited,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::{Grid, Point};
+
+    type Position = Point<usize>;
+
+    const MAZE: &str = indoc! {"
+        S...#
+        .##.#
+        ....E
+    "};
+
+    fn maze() -> (Grid<u8>, Position, Position) {
+        let grid = Grid::parse(MAZE, |_, b| b).unwrap();
+        let start = grid.find(|&b| b == b'S').unwrap();
+        let end = grid.find(|&b| b == b'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open_neighbours(grid: &Grid<u8>, position: Position) -> Vec<Position> {
+        grid.neighbours4(position)
+            .filter(|&next| grid[next] != b'#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+
+        let path = bfs(
+            start,
+            |&position| open_neighbours(&grid, position),
+            |&position| position == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+        assert_eq!((path.states[0], path.states[6]), (start, end));
+        assert!(path.visited <= 12);
+
+        assert_eq!(bfs(start, |_| [], |&position| position == end), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going down the left column costs 5, so the top corridor is cheaper
+        let (grid, start, end) = maze();
+        let successors = |&position: &Position| {
+            open_neighbours(&grid, position)
+                .into_iter()
+                .map(move |next| {
+                    let down_left_column = next.y > position.y && position.x == 0;
+                    (next, if down_left_column { 5 } else { 1 })
+                })
+        };
+
+        let path = dijkstra(start, successors, |&position| position == end).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(
+            path.states,
+            [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (4, 2)].map(|(x, y)| Point::new(x, y))
+        );
+
+        let guided = astar(
+            start,
+            successors,
+            |&position| position.manhattan(end),
+            |&position| position == end,
+        )
+        .unwrap();
+        assert_eq!((guided.cost, guided.states), (path.cost, path.states));
+        assert!(guided.visited <= path.visited);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let (grid, start, end) = maze();
+
+        let paths = all_shortest_paths(
+            start,
+            |&position| {
+                open_neighbours(&grid, position)
+                    .into_iter()
+                    .map(|next| (next, 1))
+            },
+            |&position| position == end,
+        )
+        .unwrap();
+        assert_eq!(paths.cost, 6);
+        assert_eq!(paths.goals, [end]);
+        // both ways around the walls go through every open cell
+        assert_eq!(paths.states().len(), 11);
+        let mut predecessors = paths.predecessors(&Point::new(3, 2)).to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, [Point::new(2, 2), Point::new(3, 1)]);
+        assert_eq!(paths.path().len(), 7);
+    }
+
+    #[test]
+    fn test_all_shortest_paths_zero_cost_cycle() {
+        // 0 and 1 lead to each other for free, then 1 leads to the goal 2
+        let edges = [(0, 1, 0), (1, 0, 0), (1, 2, 1)];
+
+        let paths = all_shortest_paths(
+            0,
+            |&state| {
+                edges
+                    .iter()
+                    .filter(move |&&(from, _, _)| from == state)
+                    .map(|&(_, to, cost)| (to, cost))
+            },
+            |&state| state == 2,
+        )
+        .unwrap();
+        assert_eq!(paths.cost, 1);
+        assert_eq!(paths.predecessors(&0), &[] as &[i32]);
+        assert_eq!(paths.path(), [0, 1, 2]);
+    }
+}